@@ -9,6 +9,7 @@ export function commandList(extension: Extension): Disposable[] {
   let commandList = []
   commandList.push(vscode.commands.registerCommand('mcshader.restart', restartExtension(extension)))
   commandList.push(vscode.commands.registerCommand('mcshader.virtualMerge', virtualMergedDocument(extension)))
+  commandList.push(vscode.commands.registerCommand('mcshader.openArchiveFile', openArchiveFile(extension)))
   return commandList
 }
 
@@ -67,3 +68,44 @@ function virtualMergedDocument(e: Extension): Command {
     })
   }
 }
+
+function openArchiveFile(e: Extension): Command {
+  const docProvider = new class implements vscode.TextDocumentContentProvider {
+    async provideTextDocumentContent(uri: vscode.Uri, __: vscode.CancellationToken): Promise<string> {
+      try {
+        return await e.client.sendRequest<string>(lc.ExecuteCommandRequest.type.method, {
+          command: 'archiveContent',
+          arguments: [uri.path]
+        })
+      } catch (e) {
+        log.error(e)
+        return ''
+      }
+    }
+  }
+
+  // Files in zipped shader packs are read-only documents with content from language server
+  e.context.subscriptions.push(vscode.workspace.registerTextDocumentContentProvider('mcshader-zip', docProvider))
+
+  return async () => {
+    const shaderPacks = await e.client.sendRequest<{ path: string, files: { path: string, uri: string }[] }[]>(lc.ExecuteCommandRequest.type.method, {
+      command: 'archiveFiles',
+      arguments: []
+    })
+    const items = shaderPacks.flatMap((shaderPack) => shaderPack.files.map((file) => ({
+      label: file.path,
+      description: shaderPack.path,
+      uri: file.uri
+    })))
+    if (items.length == 0) {
+      vscode.window.showInformationMessage('No zipped shader pack found in workspace')
+      return
+    }
+
+    const item = await vscode.window.showQuickPick(items, { matchOnDescription: true })
+    if (item == undefined) return
+
+    const doc = await vscode.workspace.openTextDocument(vscode.Uri.parse(item.uri))
+    await vscode.window.showTextDocument(doc, { preview: true })
+  }
+}
@@ -71,7 +71,7 @@ export class Extension {
             serverOption,
             {
                 diagnosticCollectionName: 'mcshader',
-                documentSelector: [{ scheme: 'file', language: 'glsl' }],
+                documentSelector: [{ scheme: 'file', language: 'glsl' }, { scheme: 'mcshader-zip', language: 'glsl' }],
                 synchronize: {
                     configurationSection: 'mcshader',
                 },
@@ -8,7 +8,7 @@ pub fn scope(_args: TokenStream, function: TokenStream) -> TokenStream {
     let function_name = function.sig.ident.to_string();
     let stmts = function.block.stmts;
 
-    function.block = Box::new(parse_quote!({
+    *function.block = parse_quote!({
         use logging::{slog_o, FnValue, Level, scope, logger};
         use std::thread::current;
 
@@ -16,7 +16,7 @@ pub fn scope(_args: TokenStream, function: TokenStream) -> TokenStream {
         scope(&logger().new(slog_o!("test_name" => #function_name, "thread_num" => FnValue(|_| format!("{:?}", current().id())))), || {
             #(#stmts)*
         });
-    }));
+    });
 
     TokenStream::from(quote!(#function))
 }
@@ -26,13 +26,13 @@ pub fn with_trace_id(_args: TokenStream, function: TokenStream) -> TokenStream {
     let mut function = parse_macro_input!(function as ItemFn);
     let stmts = function.block.stmts;
 
-    function.block = Box::new(parse_quote!({
+    *function.block = parse_quote!({
         use logging::{slog_o, scope, logger, new_trace_id};
 
         scope(&logger().new(slog_o!("trace" => new_trace_id())), || {
             #(#stmts)*
         })
-    }));
+    });
 
     TokenStream::from(quote!(#function))
 }
@@ -0,0 +1,175 @@
+use super::*;
+
+/// Built-in functions, as (name, overload signatures, since version, profile, stages, description)
+///
+/// `genType` means `float`, `vec2`, `vec3` or `vec4`, `genIType`, `genUType`, `genBType` and `genDType` are the same for
+/// `int`, `uint`, `bool` and `double` types. `gvec4` and `gsampler` mean the float, int and uint variants.
+#[rustfmt::skip]
+pub(super) static FUNCTIONS: &[FunctionRow] = &[
+    // Angle and trigonometry functions
+    ("radians", &["genType radians(genType degrees)"], 110, ANY, ALL, "Converts degrees to radians."),
+    ("degrees", &["genType degrees(genType radians)"], 110, ANY, ALL, "Converts radians to degrees."),
+    ("sin", &["genType sin(genType angle)"], 110, ANY, ALL, "The standard trigonometric sine function."),
+    ("cos", &["genType cos(genType angle)"], 110, ANY, ALL, "The standard trigonometric cosine function."),
+    ("tan", &["genType tan(genType angle)"], 110, ANY, ALL, "The standard trigonometric tangent function."),
+    ("asin", &["genType asin(genType x)"], 110, ANY, ALL, "Arc sine, the angle whose sine is `x`, in range [-π/2, π/2]."),
+    ("acos", &["genType acos(genType x)"], 110, ANY, ALL, "Arc cosine, the angle whose cosine is `x`, in range [0, π]."),
+    ("atan", &["genType atan(genType y, genType x)", "genType atan(genType y_over_x)"], 110, ANY, ALL, "Arc tangent. The two-argument version uses the signs of `x` and `y` to determine the quadrant, in range [-π, π]."),
+    ("sinh", &["genType sinh(genType x)"], 130, ANY, ALL, "Hyperbolic sine function."),
+    ("cosh", &["genType cosh(genType x)"], 130, ANY, ALL, "Hyperbolic cosine function."),
+    ("tanh", &["genType tanh(genType x)"], 130, ANY, ALL, "Hyperbolic tangent function."),
+    ("asinh", &["genType asinh(genType x)"], 130, ANY, ALL, "Arc hyperbolic sine, the inverse of `sinh`."),
+    ("acosh", &["genType acosh(genType x)"], 130, ANY, ALL, "Arc hyperbolic cosine, the non-negative inverse of `cosh`."),
+    ("atanh", &["genType atanh(genType x)"], 130, ANY, ALL, "Arc hyperbolic tangent, the inverse of `tanh`."),
+    // Exponential functions
+    ("pow", &["genType pow(genType x, genType y)"], 110, ANY, ALL, "Returns `x` raised to the power of `y`. Undefined if `x < 0`, or if `x == 0` and `y <= 0`."),
+    ("exp", &["genType exp(genType x)"], 110, ANY, ALL, "Returns the natural exponentiation of `x`."),
+    ("log", &["genType log(genType x)"], 110, ANY, ALL, "Returns the natural logarithm of `x`. Undefined if `x <= 0`."),
+    ("exp2", &["genType exp2(genType x)"], 110, ANY, ALL, "Returns 2 raised to the power of `x`."),
+    ("log2", &["genType log2(genType x)"], 110, ANY, ALL, "Returns the base 2 logarithm of `x`. Undefined if `x <= 0`."),
+    ("sqrt", &["genType sqrt(genType x)", "genDType sqrt(genDType x)"], 110, ANY, ALL, "Returns the square root of `x`. Undefined if `x < 0`."),
+    ("inversesqrt", &["genType inversesqrt(genType x)", "genDType inversesqrt(genDType x)"], 110, ANY, ALL, "Returns `1.0 / sqrt(x)`. Undefined if `x <= 0`."),
+    // Common functions
+    ("abs", &["genType abs(genType x)", "genIType abs(genIType x)"], 110, ANY, ALL, "Returns the absolute value of `x`."),
+    ("sign", &["genType sign(genType x)", "genIType sign(genIType x)"], 110, ANY, ALL, "Returns 1.0 if `x > 0`, 0.0 if `x == 0`, or -1.0 if `x < 0`."),
+    ("floor", &["genType floor(genType x)"], 110, ANY, ALL, "Returns the nearest integer less than or equal to `x`."),
+    ("trunc", &["genType trunc(genType x)"], 130, ANY, ALL, "Returns the nearest integer whose absolute value is not larger than that of `x`."),
+    ("round", &["genType round(genType x)"], 130, ANY, ALL, "Returns the nearest integer to `x`. The direction of rounding `0.5` is implementation dependent."),
+    ("roundEven", &["genType roundEven(genType x)"], 130, ANY, ALL, "Returns the nearest integer to `x`, rounding `0.5` to the nearest even integer."),
+    ("ceil", &["genType ceil(genType x)"], 110, ANY, ALL, "Returns the nearest integer greater than or equal to `x`."),
+    ("fract", &["genType fract(genType x)"], 110, ANY, ALL, "Returns `x - floor(x)`."),
+    ("mod", &["genType mod(genType x, float y)", "genType mod(genType x, genType y)"], 110, ANY, ALL, "Modulus, returns `x - y * floor(x / y)`."),
+    ("modf", &["genType modf(genType x, out genType i)"], 130, ANY, ALL, "Returns the fractional part of `x`, and sets `i` to the integer part."),
+    ("min", &["genType min(genType x, genType y)", "genType min(genType x, float y)", "genIType min(genIType x, genIType y)", "genIType min(genIType x, int y)", "genUType min(genUType x, genUType y)", "genUType min(genUType x, uint y)"], 110, ANY, ALL, "Returns `y` if `y < x`, otherwise returns `x`."),
+    ("max", &["genType max(genType x, genType y)", "genType max(genType x, float y)", "genIType max(genIType x, genIType y)", "genIType max(genIType x, int y)", "genUType max(genUType x, genUType y)", "genUType max(genUType x, uint y)"], 110, ANY, ALL, "Returns `y` if `x < y`, otherwise returns `x`."),
+    ("clamp", &["genType clamp(genType x, genType minVal, genType maxVal)", "genType clamp(genType x, float minVal, float maxVal)", "genIType clamp(genIType x, genIType minVal, genIType maxVal)", "genIType clamp(genIType x, int minVal, int maxVal)", "genUType clamp(genUType x, genUType minVal, genUType maxVal)", "genUType clamp(genUType x, uint minVal, uint maxVal)"], 110, ANY, ALL, "Returns `min(max(x, minVal), maxVal)`. Undefined if `minVal > maxVal`."),
+    ("mix", &["genType mix(genType x, genType y, genType a)", "genType mix(genType x, genType y, float a)", "genType mix(genType x, genType y, genBType a)"], 110, ANY, ALL, "Linear blend of `x` and `y`, returns `x * (1 - a) + y * a`. The `genBType` version selects `y` where `a` is true, available since GLSL 1.30."),
+    ("step", &["genType step(genType edge, genType x)", "genType step(float edge, genType x)"], 110, ANY, ALL, "Returns 0.0 if `x < edge`, otherwise returns 1.0."),
+    ("smoothstep", &["genType smoothstep(genType edge0, genType edge1, genType x)", "genType smoothstep(float edge0, float edge1, genType x)"], 110, ANY, ALL, "Hermite interpolation between 0.0 and 1.0 when `edge0 < x < edge1`. Undefined if `edge0 >= edge1`."),
+    ("isnan", &["genBType isnan(genType x)"], 130, ANY, ALL, "Returns true if `x` holds a NaN."),
+    ("isinf", &["genBType isinf(genType x)"], 130, ANY, ALL, "Returns true if `x` holds a positive or negative infinity."),
+    ("floatBitsToInt", &["genIType floatBitsToInt(genType value)"], 330, ANY, ALL, "Returns the encoding of a float value as a signed integer."),
+    ("floatBitsToUint", &["genUType floatBitsToUint(genType value)"], 330, ANY, ALL, "Returns the encoding of a float value as an unsigned integer."),
+    ("intBitsToFloat", &["genType intBitsToFloat(genIType value)"], 330, ANY, ALL, "Returns the float value of a signed integer encoding."),
+    ("uintBitsToFloat", &["genType uintBitsToFloat(genUType value)"], 330, ANY, ALL, "Returns the float value of an unsigned integer encoding."),
+    ("fma", &["genType fma(genType a, genType b, genType c)"], 400, ANY, ALL, "Computes `a * b + c` as a single operation."),
+    ("frexp", &["genType frexp(genType x, out genIType exp)"], 400, ANY, ALL, "Splits `x` into a significand in range [0.5, 1.0) and an integral exponent of two."),
+    ("ldexp", &["genType ldexp(genType x, genIType exp)"], 400, ANY, ALL, "Builds a float from a significand and an exponent, returns `x * 2^exp`."),
+    // Packing and unpacking functions
+    ("packUnorm2x16", &["uint packUnorm2x16(vec2 v)"], 400, ANY, ALL, "Converts each component to a 16-bit unsigned normalized integer, and packs them into a uint."),
+    ("packSnorm2x16", &["uint packSnorm2x16(vec2 v)"], 420, ANY, ALL, "Converts each component to a 16-bit signed normalized integer, and packs them into a uint."),
+    ("packUnorm4x8", &["uint packUnorm4x8(vec4 v)"], 400, ANY, ALL, "Converts each component to an 8-bit unsigned normalized integer, and packs them into a uint."),
+    ("packSnorm4x8", &["uint packSnorm4x8(vec4 v)"], 400, ANY, ALL, "Converts each component to an 8-bit signed normalized integer, and packs them into a uint."),
+    ("unpackUnorm2x16", &["vec2 unpackUnorm2x16(uint p)"], 400, ANY, ALL, "Unpacks a uint into two 16-bit unsigned normalized values."),
+    ("unpackSnorm2x16", &["vec2 unpackSnorm2x16(uint p)"], 420, ANY, ALL, "Unpacks a uint into two 16-bit signed normalized values."),
+    ("unpackUnorm4x8", &["vec4 unpackUnorm4x8(uint p)"], 400, ANY, ALL, "Unpacks a uint into four 8-bit unsigned normalized values."),
+    ("unpackSnorm4x8", &["vec4 unpackSnorm4x8(uint p)"], 400, ANY, ALL, "Unpacks a uint into four 8-bit signed normalized values."),
+    ("packHalf2x16", &["uint packHalf2x16(vec2 v)"], 420, ANY, ALL, "Converts each component to a 16-bit float, and packs them into a uint."),
+    ("unpackHalf2x16", &["vec2 unpackHalf2x16(uint v)"], 420, ANY, ALL, "Unpacks a uint into two 16-bit float values."),
+    // Geometric functions
+    ("length", &["float length(genType x)"], 110, ANY, ALL, "Returns the length of vector `x`."),
+    ("distance", &["float distance(genType p0, genType p1)"], 110, ANY, ALL, "Returns the distance between `p0` and `p1`."),
+    ("dot", &["float dot(genType x, genType y)"], 110, ANY, ALL, "Returns the dot product of `x` and `y`."),
+    ("cross", &["vec3 cross(vec3 x, vec3 y)"], 110, ANY, ALL, "Returns the cross product of `x` and `y`."),
+    ("normalize", &["genType normalize(genType x)"], 110, ANY, ALL, "Returns a vector in the same direction as `x` but with a length of 1."),
+    ("ftransform", &["vec4 ftransform()"], 110, COMPATIBILITY, VERTEX, "Transforms `gl_Vertex` exactly the same way as the fixed function pipeline."),
+    ("faceforward", &["genType faceforward(genType N, genType I, genType Nref)"], 110, ANY, ALL, "Returns `N` if `dot(Nref, I) < 0`, otherwise returns `-N`."),
+    ("reflect", &["genType reflect(genType I, genType N)"], 110, ANY, ALL, "Returns the reflection direction of incident vector `I` on surface normal `N`. `N` should be normalized."),
+    ("refract", &["genType refract(genType I, genType N, float eta)"], 110, ANY, ALL, "Returns the refraction vector of incident vector `I`, surface normal `N` and ratio of indices of refraction `eta`."),
+    // Matrix functions
+    ("matrixCompMult", &["mat matrixCompMult(mat x, mat y)"], 110, ANY, ALL, "Multiplies matrix `x` by matrix `y` component-wise."),
+    ("outerProduct", &["mat outerProduct(vec c, vec r)"], 120, ANY, ALL, "Linear algebraic matrix multiply of column vector `c` and row vector `r`."),
+    ("transpose", &["mat transpose(mat m)"], 120, ANY, ALL, "Returns the transpose of matrix `m`."),
+    ("determinant", &["float determinant(mat m)"], 150, ANY, ALL, "Returns the determinant of square matrix `m`."),
+    ("inverse", &["mat inverse(mat m)"], 140, ANY, ALL, "Returns the inverse of square matrix `m`. Undefined if `m` is singular."),
+    // Vector relational functions
+    ("lessThan", &["bvec lessThan(vec x, vec y)", "bvec lessThan(ivec x, ivec y)", "bvec lessThan(uvec x, uvec y)"], 110, ANY, ALL, "Component-wise compare of `x < y`."),
+    ("lessThanEqual", &["bvec lessThanEqual(vec x, vec y)", "bvec lessThanEqual(ivec x, ivec y)", "bvec lessThanEqual(uvec x, uvec y)"], 110, ANY, ALL, "Component-wise compare of `x <= y`."),
+    ("greaterThan", &["bvec greaterThan(vec x, vec y)", "bvec greaterThan(ivec x, ivec y)", "bvec greaterThan(uvec x, uvec y)"], 110, ANY, ALL, "Component-wise compare of `x > y`."),
+    ("greaterThanEqual", &["bvec greaterThanEqual(vec x, vec y)", "bvec greaterThanEqual(ivec x, ivec y)", "bvec greaterThanEqual(uvec x, uvec y)"], 110, ANY, ALL, "Component-wise compare of `x >= y`."),
+    ("equal", &["bvec equal(vec x, vec y)", "bvec equal(ivec x, ivec y)", "bvec equal(uvec x, uvec y)", "bvec equal(bvec x, bvec y)"], 110, ANY, ALL, "Component-wise compare of `x == y`."),
+    ("notEqual", &["bvec notEqual(vec x, vec y)", "bvec notEqual(ivec x, ivec y)", "bvec notEqual(uvec x, uvec y)", "bvec notEqual(bvec x, bvec y)"], 110, ANY, ALL, "Component-wise compare of `x != y`."),
+    ("any", &["bool any(bvec x)"], 110, ANY, ALL, "Returns true if any component of `x` is true."),
+    ("all", &["bool all(bvec x)"], 110, ANY, ALL, "Returns true only if all components of `x` are true."),
+    ("not", &["bvec not(bvec x)"], 110, ANY, ALL, "Returns the component-wise logical complement of `x`."),
+    // Integer functions
+    ("uaddCarry", &["genUType uaddCarry(genUType x, genUType y, out genUType carry)"], 400, ANY, ALL, "Adds 32-bit unsigned integers `x` and `y`, returning the sum modulo 2^32. `carry` is set to 1 if the sum overflows."),
+    ("usubBorrow", &["genUType usubBorrow(genUType x, genUType y, out genUType borrow)"], 400, ANY, ALL, "Subtracts `y` from `x`, returning the difference if non-negative, otherwise 2^32 plus the difference. `borrow` is set to 1 if `x < y`."),
+    ("umulExtended", &["void umulExtended(genUType x, genUType y, out genUType msb, out genUType lsb)"], 400, ANY, ALL, "Multiplies 32-bit unsigned integers, producing a 64-bit result split into `msb` and `lsb`."),
+    ("imulExtended", &["void imulExtended(genIType x, genIType y, out genIType msb, out genIType lsb)"], 400, ANY, ALL, "Multiplies 32-bit signed integers, producing a 64-bit result split into `msb` and `lsb`."),
+    ("bitfieldExtract", &["genIType bitfieldExtract(genIType value, int offset, int bits)", "genUType bitfieldExtract(genUType value, int offset, int bits)"], 400, ANY, ALL, "Extracts bits `[offset, offset + bits - 1]` from `value`."),
+    ("bitfieldInsert", &["genIType bitfieldInsert(genIType base, genIType insert, int offset, int bits)", "genUType bitfieldInsert(genUType base, genUType insert, int offset, int bits)"], 400, ANY, ALL, "Inserts the `bits` least significant bits of `insert` into `base` at `offset`."),
+    ("bitfieldReverse", &["genIType bitfieldReverse(genIType value)", "genUType bitfieldReverse(genUType value)"], 400, ANY, ALL, "Reverses the bits of `value`."),
+    ("bitCount", &["genIType bitCount(genIType value)", "genIType bitCount(genUType value)"], 400, ANY, ALL, "Returns the number of one bits in `value`."),
+    ("findLSB", &["genIType findLSB(genIType value)", "genIType findLSB(genUType value)"], 400, ANY, ALL, "Returns the bit number of the least significant one bit in `value`, or -1 if `value` is zero."),
+    ("findMSB", &["genIType findMSB(genIType value)", "genIType findMSB(genUType value)"], 400, ANY, ALL, "Returns the bit number of the most significant bit in `value` that differs from its sign bit, or -1 if there is none."),
+    // Texture functions
+    ("textureSize", &["ivec2 textureSize(gsampler2D sampler, int lod)", "ivec3 textureSize(gsampler3D sampler, int lod)", "ivec2 textureSize(gsamplerCube sampler, int lod)", "ivec2 textureSize(sampler2DShadow sampler, int lod)", "ivec3 textureSize(gsampler2DArray sampler, int lod)", "ivec2 textureSize(gsampler2DRect sampler)"], 130, ANY, ALL, "Returns the dimensions of level `lod` of the texture bound to `sampler`."),
+    ("textureQueryLod", &["vec2 textureQueryLod(gsampler2D sampler, vec2 P)", "vec2 textureQueryLod(gsampler3D sampler, vec3 P)"], 400, ANY, FRAGMENT, "Returns the mipmap array that would be accessed in `x`, and the computed level of detail in `y`."),
+    ("textureQueryLevels", &["int textureQueryLevels(gsampler2D sampler)", "int textureQueryLevels(gsampler3D sampler)"], 430, ANY, ALL, "Returns the number of mipmap levels of the texture bound to `sampler`."),
+    ("texture", &["gvec4 texture(gsampler2D sampler, vec2 P)", "gvec4 texture(gsampler2D sampler, vec2 P, float bias)", "gvec4 texture(gsampler3D sampler, vec3 P)", "gvec4 texture(gsampler3D sampler, vec3 P, float bias)", "gvec4 texture(gsamplerCube sampler, vec3 P)", "float texture(sampler2DShadow sampler, vec3 P)", "float texture(sampler2DShadow sampler, vec3 P, float bias)", "gvec4 texture(gsampler2DArray sampler, vec3 P)", "gvec4 texture(gsampler2DRect sampler, vec2 P)"], 130, ANY, ALL, "Samples the texture bound to `sampler` at coordinate `P`. `bias` is added to the computed level of detail, and is only available in fragment shaders. Shadow samplers compare `P.z` with the depth and return the comparison result."),
+    ("textureProj", &["gvec4 textureProj(gsampler2D sampler, vec3 P)", "gvec4 textureProj(gsampler2D sampler, vec4 P)", "gvec4 textureProj(gsampler3D sampler, vec4 P)", "float textureProj(sampler2DShadow sampler, vec4 P)"], 130, ANY, ALL, "Samples the texture with projection, the coordinate is divided by its last component."),
+    ("textureLod", &["gvec4 textureLod(gsampler2D sampler, vec2 P, float lod)", "gvec4 textureLod(gsampler3D sampler, vec3 P, float lod)", "gvec4 textureLod(gsamplerCube sampler, vec3 P, float lod)", "float textureLod(sampler2DShadow sampler, vec3 P, float lod)", "gvec4 textureLod(gsampler2DArray sampler, vec3 P, float lod)"], 130, ANY, ALL, "Samples the texture with an explicit level of detail `lod`."),
+    ("textureOffset", &["gvec4 textureOffset(gsampler2D sampler, vec2 P, ivec2 offset)", "gvec4 textureOffset(gsampler2D sampler, vec2 P, ivec2 offset, float bias)", "gvec4 textureOffset(gsampler3D sampler, vec3 P, ivec3 offset)", "float textureOffset(sampler2DShadow sampler, vec3 P, ivec2 offset)"], 130, ANY, ALL, "Samples the texture with a constant texel `offset` applied to the coordinate."),
+    ("texelFetch", &["gvec4 texelFetch(gsampler2D sampler, ivec2 P, int lod)", "gvec4 texelFetch(gsampler3D sampler, ivec3 P, int lod)", "gvec4 texelFetch(gsampler2DArray sampler, ivec3 P, int lod)", "gvec4 texelFetch(gsampler2DRect sampler, ivec2 P)"], 130, ANY, ALL, "Fetches a single texel at integer coordinate `P` of level `lod`, without filtering."),
+    ("texelFetchOffset", &["gvec4 texelFetchOffset(gsampler2D sampler, ivec2 P, int lod, ivec2 offset)", "gvec4 texelFetchOffset(gsampler3D sampler, ivec3 P, int lod, ivec3 offset)"], 130, ANY, ALL, "Fetches a single texel with a constant `offset` applied to the coordinate."),
+    ("textureProjOffset", &["gvec4 textureProjOffset(gsampler2D sampler, vec3 P, ivec2 offset)", "gvec4 textureProjOffset(gsampler2D sampler, vec4 P, ivec2 offset)"], 130, ANY, ALL, "Samples the texture with projection and a constant texel offset."),
+    ("textureLodOffset", &["gvec4 textureLodOffset(gsampler2D sampler, vec2 P, float lod, ivec2 offset)", "gvec4 textureLodOffset(gsampler3D sampler, vec3 P, float lod, ivec3 offset)"], 130, ANY, ALL, "Samples the texture with an explicit level of detail and a constant texel offset."),
+    ("textureProjLod", &["gvec4 textureProjLod(gsampler2D sampler, vec3 P, float lod)", "gvec4 textureProjLod(gsampler2D sampler, vec4 P, float lod)"], 130, ANY, ALL, "Samples the texture with projection and an explicit level of detail."),
+    ("textureGrad", &["gvec4 textureGrad(gsampler2D sampler, vec2 P, vec2 dPdx, vec2 dPdy)", "gvec4 textureGrad(gsampler3D sampler, vec3 P, vec3 dPdx, vec3 dPdy)", "gvec4 textureGrad(gsamplerCube sampler, vec3 P, vec3 dPdx, vec3 dPdy)", "float textureGrad(sampler2DShadow sampler, vec3 P, vec2 dPdx, vec2 dPdy)"], 130, ANY, ALL, "Samples the texture with explicit gradients to compute the level of detail."),
+    ("textureGradOffset", &["gvec4 textureGradOffset(gsampler2D sampler, vec2 P, vec2 dPdx, vec2 dPdy, ivec2 offset)"], 130, ANY, ALL, "Samples the texture with explicit gradients and a constant texel offset."),
+    ("textureGather", &["gvec4 textureGather(gsampler2D sampler, vec2 P)", "gvec4 textureGather(gsampler2D sampler, vec2 P, int comp)", "vec4 textureGather(sampler2DShadow sampler, vec2 P, float refZ)"], 400, ANY, ALL, "Gathers component `comp` of the four texels used for bilinear filtering."),
+    ("textureGatherOffset", &["gvec4 textureGatherOffset(gsampler2D sampler, vec2 P, ivec2 offset)", "gvec4 textureGatherOffset(gsampler2D sampler, vec2 P, ivec2 offset, int comp)"], 400, ANY, ALL, "Gathers the four texels used for bilinear filtering with a texel offset."),
+    ("texture1D", &["vec4 texture1D(sampler1D sampler, float coord)", "vec4 texture1D(sampler1D sampler, float coord, float bias)"], 110, COMPATIBILITY, ALL, "Deprecated 1D texture lookup, use `texture` instead."),
+    ("texture2D", &["vec4 texture2D(sampler2D sampler, vec2 coord)", "vec4 texture2D(sampler2D sampler, vec2 coord, float bias)"], 110, COMPATIBILITY, ALL, "Deprecated 2D texture lookup, use `texture` instead."),
+    ("texture2DLod", &["vec4 texture2DLod(sampler2D sampler, vec2 coord, float lod)"], 110, COMPATIBILITY, ALL, "Deprecated 2D texture lookup with explicit level of detail, use `textureLod` instead."),
+    ("texture2DProj", &["vec4 texture2DProj(sampler2D sampler, vec3 coord)", "vec4 texture2DProj(sampler2D sampler, vec4 coord)"], 110, COMPATIBILITY, ALL, "Deprecated 2D texture lookup with projection, use `textureProj` instead."),
+    ("texture3D", &["vec4 texture3D(sampler3D sampler, vec3 coord)", "vec4 texture3D(sampler3D sampler, vec3 coord, float bias)"], 110, COMPATIBILITY, ALL, "Deprecated 3D texture lookup, use `texture` instead."),
+    ("textureCube", &["vec4 textureCube(samplerCube sampler, vec3 coord)", "vec4 textureCube(samplerCube sampler, vec3 coord, float bias)"], 110, COMPATIBILITY, ALL, "Deprecated cube map texture lookup, use `texture` instead."),
+    ("shadow2D", &["vec4 shadow2D(sampler2DShadow sampler, vec3 coord)", "vec4 shadow2D(sampler2DShadow sampler, vec3 coord, float bias)"], 110, COMPATIBILITY, ALL, "Deprecated depth comparison lookup, use `texture` instead. Requires hardware filtering on the shadow map."),
+    ("shadow2DLod", &["vec4 shadow2DLod(sampler2DShadow sampler, vec3 coord, float lod)"], 110, COMPATIBILITY, ALL, "Deprecated depth comparison lookup with explicit level of detail, use `textureLod` instead."),
+    // Image functions
+    ("imageSize", &["ivec2 imageSize(gimage2D image)", "ivec3 imageSize(gimage3D image)"], 430, ANY, ALL, "Returns the dimensions of the image."),
+    ("imageLoad", &["gvec4 imageLoad(gimage2D image, ivec2 P)", "gvec4 imageLoad(gimage3D image, ivec3 P)"], 420, ANY, ALL, "Loads the texel at coordinate `P` from the image."),
+    ("imageStore", &["void imageStore(gimage2D image, ivec2 P, gvec4 data)", "void imageStore(gimage3D image, ivec3 P, gvec4 data)"], 420, ANY, ALL, "Stores `data` into the texel at coordinate `P` of the image."),
+    ("imageAtomicAdd", &["uint imageAtomicAdd(uimage2D image, ivec2 P, uint data)", "int imageAtomicAdd(iimage2D image, ivec2 P, int data)"], 420, ANY, ALL, "Atomically adds `data` to the texel, returning the original value."),
+    ("imageAtomicMin", &["uint imageAtomicMin(uimage2D image, ivec2 P, uint data)", "int imageAtomicMin(iimage2D image, ivec2 P, int data)"], 420, ANY, ALL, "Atomically stores the minimum of `data` and the texel, returning the original value."),
+    ("imageAtomicMax", &["uint imageAtomicMax(uimage2D image, ivec2 P, uint data)", "int imageAtomicMax(iimage2D image, ivec2 P, int data)"], 420, ANY, ALL, "Atomically stores the maximum of `data` and the texel, returning the original value."),
+    ("imageAtomicAnd", &["uint imageAtomicAnd(uimage2D image, ivec2 P, uint data)", "int imageAtomicAnd(iimage2D image, ivec2 P, int data)"], 420, ANY, ALL, "Atomically stores the bitwise AND of `data` and the texel, returning the original value."),
+    ("imageAtomicOr", &["uint imageAtomicOr(uimage2D image, ivec2 P, uint data)", "int imageAtomicOr(iimage2D image, ivec2 P, int data)"], 420, ANY, ALL, "Atomically stores the bitwise OR of `data` and the texel, returning the original value."),
+    ("imageAtomicXor", &["uint imageAtomicXor(uimage2D image, ivec2 P, uint data)", "int imageAtomicXor(iimage2D image, ivec2 P, int data)"], 420, ANY, ALL, "Atomically stores the bitwise XOR of `data` and the texel, returning the original value."),
+    ("imageAtomicExchange", &["uint imageAtomicExchange(uimage2D image, ivec2 P, uint data)", "int imageAtomicExchange(iimage2D image, ivec2 P, int data)", "float imageAtomicExchange(image2D image, ivec2 P, float data)"], 420, ANY, ALL, "Atomically stores `data` into the texel, returning the original value."),
+    ("imageAtomicCompSwap", &["uint imageAtomicCompSwap(uimage2D image, ivec2 P, uint compare, uint data)", "int imageAtomicCompSwap(iimage2D image, ivec2 P, int compare, int data)"], 420, ANY, ALL, "Atomically stores `data` into the texel if it equals `compare`, returning the original value."),
+    // Atomic memory functions
+    ("atomicAdd", &["uint atomicAdd(inout uint mem, uint data)", "int atomicAdd(inout int mem, int data)"], 430, ANY, ALL, "Atomically adds `data` to `mem`, returning the original value."),
+    ("atomicMin", &["uint atomicMin(inout uint mem, uint data)", "int atomicMin(inout int mem, int data)"], 430, ANY, ALL, "Atomically stores the minimum of `data` and `mem`, returning the original value."),
+    ("atomicMax", &["uint atomicMax(inout uint mem, uint data)", "int atomicMax(inout int mem, int data)"], 430, ANY, ALL, "Atomically stores the maximum of `data` and `mem`, returning the original value."),
+    ("atomicAnd", &["uint atomicAnd(inout uint mem, uint data)", "int atomicAnd(inout int mem, int data)"], 430, ANY, ALL, "Atomically stores the bitwise AND of `data` and `mem`, returning the original value."),
+    ("atomicOr", &["uint atomicOr(inout uint mem, uint data)", "int atomicOr(inout int mem, int data)"], 430, ANY, ALL, "Atomically stores the bitwise OR of `data` and `mem`, returning the original value."),
+    ("atomicXor", &["uint atomicXor(inout uint mem, uint data)", "int atomicXor(inout int mem, int data)"], 430, ANY, ALL, "Atomically stores the bitwise XOR of `data` and `mem`, returning the original value."),
+    ("atomicExchange", &["uint atomicExchange(inout uint mem, uint data)", "int atomicExchange(inout int mem, int data)"], 430, ANY, ALL, "Atomically stores `data` into `mem`, returning the original value."),
+    ("atomicCompSwap", &["uint atomicCompSwap(inout uint mem, uint compare, uint data)", "int atomicCompSwap(inout int mem, int compare, int data)"], 430, ANY, ALL, "Atomically stores `data` into `mem` if it equals `compare`, returning the original value."),
+    // Fragment processing functions
+    ("dFdx", &["genType dFdx(genType p)"], 110, ANY, FRAGMENT, "Returns the derivative of `p` in screen space x direction."),
+    ("dFdy", &["genType dFdy(genType p)"], 110, ANY, FRAGMENT, "Returns the derivative of `p` in screen space y direction."),
+    ("fwidth", &["genType fwidth(genType p)"], 110, ANY, FRAGMENT, "Returns `abs(dFdx(p)) + abs(dFdy(p))`."),
+    ("dFdxFine", &["genType dFdxFine(genType p)"], 450, ANY, FRAGMENT, "Returns the x derivative of `p` computed from the current fragment and its immediate neighbors."),
+    ("dFdyFine", &["genType dFdyFine(genType p)"], 450, ANY, FRAGMENT, "Returns the y derivative of `p` computed from the current fragment and its immediate neighbors."),
+    ("dFdxCoarse", &["genType dFdxCoarse(genType p)"], 450, ANY, FRAGMENT, "Returns the x derivative of `p`, possibly shared by neighboring fragments."),
+    ("dFdyCoarse", &["genType dFdyCoarse(genType p)"], 450, ANY, FRAGMENT, "Returns the y derivative of `p`, possibly shared by neighboring fragments."),
+    ("interpolateAtCentroid", &["genType interpolateAtCentroid(genType interpolant)"], 400, ANY, FRAGMENT, "Returns the value of the input `interpolant` sampled at the centroid of the pixel."),
+    ("interpolateAtSample", &["genType interpolateAtSample(genType interpolant, int sample)"], 400, ANY, FRAGMENT, "Returns the value of the input `interpolant` at the location of sample number `sample`."),
+    ("interpolateAtOffset", &["genType interpolateAtOffset(genType interpolant, vec2 offset)"], 400, ANY, FRAGMENT, "Returns the value of the input `interpolant` sampled at an offset from the pixel center."),
+    // Geometry shader functions
+    ("EmitVertex", &["void EmitVertex()"], 150, ANY, GEOMETRY, "Emits the current values of output variables as a new vertex of the current primitive."),
+    ("EndPrimitive", &["void EndPrimitive()"], 150, ANY, GEOMETRY, "Completes the current output primitive and starts a new one."),
+    ("EmitStreamVertex", &["void EmitStreamVertex(int stream)"], 400, ANY, GEOMETRY, "Emits a new vertex to vertex stream `stream`."),
+    ("EndStreamPrimitive", &["void EndStreamPrimitive(int stream)"], 400, ANY, GEOMETRY, "Completes the current output primitive on vertex stream `stream`."),
+    // Shader invocation control and memory control functions
+    ("barrier", &["void barrier()"], 430, ANY, COMPUTE, "Waits until all invocations in the work group reach this barrier."),
+    ("memoryBarrier", &["void memoryBarrier()"], 420, ANY, ALL, "Controls the ordering of all memory transactions issued by this invocation."),
+    ("memoryBarrierShared", &["void memoryBarrierShared()"], 430, ANY, COMPUTE, "Controls the ordering of shared variable memory transactions issued by this invocation."),
+    ("memoryBarrierImage", &["void memoryBarrierImage()"], 430, ANY, ALL, "Controls the ordering of image memory transactions issued by this invocation."),
+    ("memoryBarrierBuffer", &["void memoryBarrierBuffer()"], 430, ANY, ALL, "Controls the ordering of buffer variable memory transactions issued by this invocation."),
+    ("groupMemoryBarrier", &["void groupMemoryBarrier()"], 430, ANY, COMPUTE, "Controls the ordering of memory transactions issued by this invocation, as seen by other invocations in the same work group."),
+];
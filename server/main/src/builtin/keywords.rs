@@ -0,0 +1,21 @@
+/// GLSL keywords, including types, qualifiers and control flow statements
+#[rustfmt::skip]
+pub static KEYWORDS: &[&str] = &[
+    // Types
+    "void", "bool", "int", "uint", "float", "double",
+    "vec2", "vec3", "vec4", "dvec2", "dvec3", "dvec4", "bvec2", "bvec3", "bvec4", "ivec2", "ivec3", "ivec4", "uvec2", "uvec3", "uvec4",
+    "mat2", "mat3", "mat4", "mat2x2", "mat2x3", "mat2x4", "mat3x2", "mat3x3", "mat3x4", "mat4x2", "mat4x3", "mat4x4",
+    "sampler1D", "sampler2D", "sampler3D", "samplerCube", "sampler2DRect", "sampler1DArray", "sampler2DArray", "samplerBuffer",
+    "sampler1DShadow", "sampler2DShadow", "samplerCubeShadow", "sampler2DRectShadow", "sampler2DArrayShadow",
+    "isampler2D", "isampler3D", "usampler2D", "usampler3D",
+    "image2D", "image3D", "iimage2D", "iimage3D", "uimage2D", "uimage3D",
+    "struct",
+    // Qualifiers
+    "const", "uniform", "in", "out", "inout", "attribute", "varying", "buffer", "shared",
+    "layout", "centroid", "flat", "smooth", "noperspective", "invariant", "precise",
+    "highp", "mediump", "lowp", "precision",
+    "coherent", "volatile", "restrict", "readonly", "writeonly",
+    // Statements
+    "if", "else", "switch", "case", "default", "for", "while", "do", "break", "continue", "return", "discard",
+    "true", "false",
+];
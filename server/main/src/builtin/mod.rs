@@ -0,0 +1,263 @@
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use crate::constant::RE_MACRO_VERSION;
+use crate::pipeline::{Loader, ShaderStage};
+
+mod functions;
+mod keywords;
+mod uniforms;
+mod variables;
+
+pub use keywords::KEYWORDS;
+
+/// GLSL profiles a built-in is available in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Profile {
+    Any,
+    /// Removed from core profile since GLSL 1.40
+    Compatibility,
+}
+
+/// `#version` directive of a shader, deciding which GLSL built-ins it can use.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GlslVersion {
+    pub number: u16,
+    /// Declared with `compatibility` profile, shaders since GLSL 1.50 are core profile by default
+    pub compatibility: bool,
+}
+
+impl GlslVersion {
+    /// Version declared by the first `#version` line in this content
+    pub fn parse(content: &str) -> Option<Self> {
+        content.lines().find_map(|line| {
+            let captures = RE_MACRO_VERSION.captures(line)?;
+            Some(GlslVersion {
+                number: captures.get(1)?.as_str().parse().ok()?,
+                compatibility: captures.get(2).is_some_and(|profile| profile.as_str().trim() == "compatibility"),
+            })
+        })
+    }
+
+    /// Whether built-ins of this profile are available, compatibility ones are removed since GLSL 1.40 unless requested
+    fn has_profile(&self, profile: Profile) -> bool {
+        profile == Profile::Any || self.number < 140 || self.compatibility
+    }
+}
+
+impl std::fmt::Display for GlslVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#version {}", self.number)?;
+        if self.compatibility {
+            f.write_str(" compatibility")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BuiltinKind {
+    Function,
+    Variable,
+    Uniform,
+    Attribute,
+}
+
+const ANY: Profile = Profile::Any;
+const COMPATIBILITY: Profile = Profile::Compatibility;
+
+const ALL: &[ShaderStage] = &[];
+const VERTEX: &[ShaderStage] = &[ShaderStage::Vertex];
+const GEOMETRY: &[ShaderStage] = &[ShaderStage::Geometry];
+const FRAGMENT: &[ShaderStage] = &[ShaderStage::Fragment];
+const COMPUTE: &[ShaderStage] = &[ShaderStage::Compute];
+const VERTEX_GEOMETRY: &[ShaderStage] = &[ShaderStage::Vertex, ShaderStage::Geometry];
+const VERTEX_FRAGMENT: &[ShaderStage] = &[ShaderStage::Vertex, ShaderStage::Fragment];
+const GEOMETRY_FRAGMENT: &[ShaderStage] = &[ShaderStage::Geometry, ShaderStage::Fragment];
+
+const BOTH: &[Loader] = &[Loader::OptiFine, Loader::Iris];
+const OPTIFINE: &[Loader] = &[Loader::OptiFine];
+const IRIS: &[Loader] = &[Loader::Iris];
+
+const ALL_PROGRAMS: &[&str] = &[];
+
+/// (name, overload signatures, since version, profile, stages, description)
+type FunctionRow = (
+    &'static str,
+    &'static [&'static str],
+    u16,
+    Profile,
+    &'static [ShaderStage],
+    &'static str,
+);
+/// (name, declaration, since version, profile, stages, description)
+type VariableRow = (&'static str, &'static str, u16, Profile, &'static [ShaderStage], &'static str);
+/// (name, declaration, loaders, programs, description)
+type UniformRow = (&'static str, &'static str, &'static [Loader], &'static [&'static str], &'static str);
+
+/// A built-in function or variable of GLSL, or a uniform or attribute provided by shader loaders.
+pub struct Builtin {
+    pub name: &'static str,
+    pub kind: BuiltinKind,
+    /// Signatures of all overloads of functions, or the declaration of variables
+    pub signatures: &'static [&'static str],
+    /// Minimal `#version` of GLSL built-ins, 0 for uniforms and attributes
+    pub since: u16,
+    pub profile: Profile,
+    /// Shader stages available in, empty means all stages
+    pub stages: &'static [ShaderStage],
+    /// Shader loaders providing it, empty for GLSL built-ins
+    pub loaders: &'static [Loader],
+    /// Programs available in, empty means all programs
+    pub programs: &'static [&'static str],
+    pub description: &'static str,
+}
+
+lazy_static! {
+    static ref BUILTINS: HashMap<&'static str, Vec<Builtin>> = {
+        let mut builtins: HashMap<&'static str, Vec<Builtin>> = HashMap::new();
+        for (name, signatures, since, profile, stages, description) in functions::FUNCTIONS {
+            builtins.entry(name).or_default().push(Builtin {
+                name,
+                kind: BuiltinKind::Function,
+                signatures,
+                since: *since,
+                profile: *profile,
+                stages,
+                loaders: &[],
+                programs: ALL_PROGRAMS,
+                description,
+            });
+        }
+        for (name, declaration, since, profile, stages, description) in variables::VARIABLES {
+            builtins.entry(name).or_default().push(Builtin {
+                name,
+                kind: BuiltinKind::Variable,
+                signatures: std::slice::from_ref(declaration),
+                since: *since,
+                profile: *profile,
+                stages,
+                loaders: &[],
+                programs: ALL_PROGRAMS,
+                description,
+            });
+        }
+        let loader_builtins = uniforms::UNIFORMS
+            .iter()
+            .map(|row| (row, BuiltinKind::Uniform, ALL))
+            .chain(uniforms::ATTRIBUTES.iter().map(|row| (row, BuiltinKind::Attribute, VERTEX)));
+        for ((name, declaration, loaders, programs, description), kind, stages) in loader_builtins {
+            builtins.entry(name).or_default().push(Builtin {
+                name,
+                kind,
+                signatures: std::slice::from_ref(declaration),
+                since: 0,
+                profile: ANY,
+                stages,
+                loaders,
+                programs,
+                description,
+            });
+        }
+        builtins
+    };
+}
+
+/// Built-ins with this name. A name may be used by both a GLSL built-in and a loader uniform, like `texture`.
+pub fn builtins_named(name: &str) -> &'static [Builtin] {
+    BUILTINS.get(name).map(|builtins| builtins.as_slice()).unwrap_or_default()
+}
+
+/// All built-ins, in no particular order
+pub fn builtins() -> impl Iterator<Item = &'static Builtin> {
+    BUILTINS.values().flatten()
+}
+
+fn stage_name(stage: &ShaderStage) -> &'static str {
+    match stage {
+        ShaderStage::Vertex => "vertex",
+        ShaderStage::Geometry => "geometry",
+        ShaderStage::Fragment => "fragment",
+        ShaderStage::Compute => "compute",
+    }
+}
+
+impl Builtin {
+    /// Whether it is available in a shader of this program and `#version`, a program name like `composite` without index.
+    ///
+    /// Unknown programs or versions are not checked.
+    pub fn is_available(&self, shader: Option<(&str, ShaderStage)>, version: Option<GlslVersion>) -> bool {
+        shader.is_none_or(|(program, stage)| self.in_shader(program, stage)) && version.is_none_or(|version| self.in_version(version))
+    }
+
+    fn in_shader(&self, program: &str, stage: ShaderStage) -> bool {
+        let in_program = self.programs.is_empty()
+            || self.programs.iter().any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => program.starts_with(prefix),
+                None => program == *pattern,
+            });
+        in_program && (self.stages.is_empty() || self.stages.contains(&stage))
+    }
+
+    /// Whether it is available in shaders of this `#version` and profile
+    pub fn in_version(&self, version: GlslVersion) -> bool {
+        self.since <= version.number && version.has_profile(self.profile)
+    }
+
+    /// Availability of versions, profiles, stages, loaders and programs, like `GLSL 1.30+ · fragment shaders only`.
+    pub fn availability(&self) -> String {
+        let mut availability = vec![];
+        if self.since > 110 {
+            availability.push(format!("GLSL {}.{:02}+", self.since / 100, self.since % 100));
+        }
+        if self.profile == Profile::Compatibility {
+            availability.push("compatibility profile only".to_owned());
+        }
+        if !self.stages.is_empty() {
+            let stages = self.stages.iter().map(stage_name).collect::<Vec<_>>();
+            availability.push(stages.join(" and ") + " shaders only");
+        }
+        match self.loaders {
+            [] => {}
+            [Loader::OptiFine] => availability.push("OptiFine only".to_owned()),
+            [Loader::Iris] => availability.push("Iris only".to_owned()),
+            _ => availability.push("OptiFine and Iris".to_owned()),
+        }
+        if !self.programs.is_empty() {
+            let programs = self.programs.iter().map(|program| format!("`{}`", program)).collect::<Vec<_>>();
+            availability.push("programs ".to_owned() + &programs.join(", "));
+        }
+        availability.join(" · ")
+    }
+
+    /// Byte offsets of parameters in a function signature, like `x` and `y` in `float dot(genType x, genType y)`
+    pub fn parameter_offsets(signature: &str) -> Vec<(usize, usize)> {
+        let (start, end) = match (signature.find('('), signature.rfind(')')) {
+            (Some(start), Some(end)) if signature[start + 1..end].trim().is_empty() => return vec![],
+            (Some(start), Some(end)) => (start + 1, end),
+            _ => return vec![],
+        };
+        signature[start..end]
+            .split(',')
+            .scan(start, |parameter_start, parameter| {
+                let offset = *parameter_start + parameter.len() - parameter.trim_start().len();
+                *parameter_start += parameter.len() + 1;
+                Some((offset, offset + parameter.trim().len()))
+            })
+            .collect()
+    }
+
+    /// Description followed by availability
+    pub fn documentation(&self) -> String {
+        let availability = self.availability();
+        let mut chars = availability.chars();
+        match chars.next() {
+            Some(first) => format!("{}\n\n*{}{}*", self.description, first.to_uppercase(), chars.as_str()),
+            None => self.description.to_owned(),
+        }
+    }
+
+    pub fn markdown(&self) -> String {
+        format!("```glsl\n{};\n```\n\n{}", self.signatures.join(";\n"), self.documentation())
+    }
+}
@@ -0,0 +1,177 @@
+use super::*;
+
+/// Uniforms provided by shader loaders, as (name, declaration, loaders, programs, description)
+///
+/// Programs ending with `*` match all programs with this prefix, no programs means available in all programs.
+#[rustfmt::skip]
+pub(super) static UNIFORMS: &[UniformRow] = &[
+    // Player and held items
+    ("heldItemId", "uniform int heldItemId", BOTH, ALL_PROGRAMS, "Item id of the item in main hand, mapped by `item.properties`. -1 if not mapped."),
+    ("heldBlockLightValue", "uniform int heldBlockLightValue", BOTH, ALL_PROGRAMS, "Light level of the item in main hand, from 0 to 15."),
+    ("heldItemId2", "uniform int heldItemId2", BOTH, ALL_PROGRAMS, "Item id of the item in off hand, mapped by `item.properties`. -1 if not mapped."),
+    ("heldBlockLightValue2", "uniform int heldBlockLightValue2", BOTH, ALL_PROGRAMS, "Light level of the item in off hand, from 0 to 15."),
+    ("isEyeInWater", "uniform int isEyeInWater", BOTH, ALL_PROGRAMS, "Fluid the camera is in, 0 for air, 1 for water, 2 for lava and 3 for powder snow."),
+    ("eyeAltitude", "uniform float eyeAltitude", BOTH, ALL_PROGRAMS, "Y coordinate of the player eye."),
+    ("eyeBrightness", "uniform ivec2 eyeBrightness", BOTH, ALL_PROGRAMS, "Block light in `x` and sky light in `y` at the player eye, from 0 to 240."),
+    ("eyeBrightnessSmooth", "uniform ivec2 eyeBrightnessSmooth", BOTH, ALL_PROGRAMS, "`eyeBrightness` smoothed over time, configured by `eyeBrightnessHalflife`."),
+    ("nightVision", "uniform float nightVision", BOTH, ALL_PROGRAMS, "Strength of the night vision effect, from 0.0 to 1.0."),
+    ("blindness", "uniform float blindness", BOTH, ALL_PROGRAMS, "Strength of the blindness effect, from 0.0 to 1.0."),
+    ("darknessFactor", "uniform float darknessFactor", BOTH, ALL_PROGRAMS, "Strength of the darkness effect, from 0.0 to 1.0."),
+    ("darknessLightFactor", "uniform float darknessLightFactor", BOTH, ALL_PROGRAMS, "Pulsing light reduction of the darkness effect, from 0.0 to 1.0."),
+    ("playerMood", "uniform float playerMood", BOTH, ALL_PROGRAMS, "Cave ambience mood of the player, from 0.0 to 1.0."),
+    ("hideGUI", "uniform int hideGUI", BOTH, ALL_PROGRAMS, "1 if the GUI is hidden with F1, otherwise 0."),
+    ("screenBrightness", "uniform float screenBrightness", BOTH, ALL_PROGRAMS, "Brightness from video settings, from 0.0 to 1.0."),
+    ("bossBattle", "uniform int bossBattle", OPTIFINE, ALL_PROGRAMS, "Current boss battle, 0 for none, 1 for custom, 2 for ender dragon, 3 for wither and 4 for raid."),
+    ("currentPlayerHealth", "uniform float currentPlayerHealth", IRIS, ALL_PROGRAMS, "Health of the player divided by max health, from 0.0 to 1.0. -1.0 if not in survival mode."),
+    ("maxPlayerHealth", "uniform float maxPlayerHealth", IRIS, ALL_PROGRAMS, "Max health of the player. -1.0 if not in survival mode."),
+    ("currentPlayerHunger", "uniform float currentPlayerHunger", IRIS, ALL_PROGRAMS, "Food level of the player divided by max food level, from 0.0 to 1.0. -1.0 if not in survival mode."),
+    ("maxPlayerHunger", "uniform float maxPlayerHunger", IRIS, ALL_PROGRAMS, "Max food level of the player, 20.0. -1.0 if not in survival mode."),
+    ("currentPlayerAir", "uniform float currentPlayerAir", IRIS, ALL_PROGRAMS, "Air supply of the player divided by max air supply, from 0.0 to 1.0. -1.0 if not in survival mode."),
+    ("maxPlayerAir", "uniform float maxPlayerAir", IRIS, ALL_PROGRAMS, "Max air supply of the player. -1.0 if not in survival mode."),
+    ("firstPersonCamera", "uniform bool firstPersonCamera", IRIS, ALL_PROGRAMS, "Whether the camera is in first person."),
+    ("isSpectator", "uniform bool isSpectator", IRIS, ALL_PROGRAMS, "Whether the player is in spectator mode."),
+    ("eyePosition", "uniform vec3 eyePosition", IRIS, ALL_PROGRAMS, "World space position of the player eye, which differs from `cameraPosition` in third person."),
+    ("relativeEyePosition", "uniform vec3 relativeEyePosition", IRIS, ALL_PROGRAMS, "Position of the player eye relative to the camera, `eyePosition - cameraPosition`."),
+    ("playerLookVector", "uniform vec3 playerLookVector", IRIS, ALL_PROGRAMS, "World space direction the player is looking at."),
+    ("playerBodyVector", "uniform vec3 playerBodyVector", IRIS, ALL_PROGRAMS, "World space direction the player body is facing."),
+    ("currentRenderedItemId", "uniform int currentRenderedItemId", IRIS, &["gbuffers_hand", "gbuffers_hand_water", "gbuffers_entities*", "gbuffers_block*", "shadow"], "Item id of the item or armor being rendered, mapped by `item.properties`."),
+    // Time and weather
+    ("worldTime", "uniform int worldTime", BOTH, ALL_PROGRAMS, "Time of the day in ticks, from 0 to 23999."),
+    ("worldDay", "uniform int worldDay", BOTH, ALL_PROGRAMS, "Number of days passed in the world, `worldTime / 24000`."),
+    ("moonPhase", "uniform int moonPhase", BOTH, ALL_PROGRAMS, "Moon phase, from 0 to 7."),
+    ("frameCounter", "uniform int frameCounter", BOTH, ALL_PROGRAMS, "Number of frames rendered, resets to 0 after 720719."),
+    ("frameTime", "uniform float frameTime", BOTH, ALL_PROGRAMS, "Duration of the last frame in seconds."),
+    ("frameTimeCounter", "uniform float frameTimeCounter", BOTH, ALL_PROGRAMS, "Run time in seconds, resets to 0 after 3600."),
+    ("sunAngle", "uniform float sunAngle", BOTH, ALL_PROGRAMS, "Angle of the sun, from 0.0 to 1.0. 0.0 is sunrise, 0.25 is noon, 0.5 is sunset and 0.75 is midnight."),
+    ("shadowAngle", "uniform float shadowAngle", BOTH, ALL_PROGRAMS, "Angle of the shadow light, from 0.0 to 0.5."),
+    ("rainStrength", "uniform float rainStrength", BOTH, ALL_PROGRAMS, "Strength of rain, from 0.0 to 1.0."),
+    ("wetness", "uniform float wetness", BOTH, ALL_PROGRAMS, "`rainStrength` smoothed over time, configured by `wetnessHalflife` and `drynessHalflife`."),
+    ("thunderStrength", "uniform float thunderStrength", IRIS, ALL_PROGRAMS, "Strength of thunder, from 0.0 to 1.0."),
+    ("lightningBoltPosition", "uniform vec4 lightningBoltPosition", IRIS, ALL_PROGRAMS, "Position of the lightning bolt relative to the camera in `xyz`, `w` is 1.0 if there is a lightning bolt, otherwise 0.0."),
+    ("cloudTime", "uniform float cloudTime", IRIS, ALL_PROGRAMS, "Time used to animate vanilla clouds."),
+    ("currentDate", "uniform ivec3 currentDate", IRIS, ALL_PROGRAMS, "Current system date, as year, month and day."),
+    ("currentTime", "uniform ivec3 currentTime", IRIS, ALL_PROGRAMS, "Current system time, as hour, minute and second."),
+    ("currentYearTime", "uniform ivec2 currentYearTime", IRIS, ALL_PROGRAMS, "Seconds passed since the beginning of the year in `x`, and seconds left until the end of the year in `y`."),
+    // Fog and sky
+    ("fogMode", "uniform int fogMode", BOTH, ALL_PROGRAMS, "Fog mode, `GL_LINEAR`, `GL_EXP` or `GL_EXP2`."),
+    ("fogShape", "uniform int fogShape", BOTH, ALL_PROGRAMS, "Fog shape, 0 for sphere and 1 for cylinder."),
+    ("fogStart", "uniform float fogStart", BOTH, ALL_PROGRAMS, "Start distance of the fog."),
+    ("fogEnd", "uniform float fogEnd", BOTH, ALL_PROGRAMS, "End distance of the fog."),
+    ("fogDensity", "uniform float fogDensity", BOTH, ALL_PROGRAMS, "Density of the fog, from 0.0 to 1.0."),
+    ("fogColor", "uniform vec3 fogColor", BOTH, ALL_PROGRAMS, "Color of the fog."),
+    ("skyColor", "uniform vec3 skyColor", BOTH, ALL_PROGRAMS, "Color of the sky."),
+    // Positions
+    ("sunPosition", "uniform vec3 sunPosition", BOTH, ALL_PROGRAMS, "View space position of the sun, with a length of 100."),
+    ("moonPosition", "uniform vec3 moonPosition", BOTH, ALL_PROGRAMS, "View space position of the moon, with a length of 100."),
+    ("shadowLightPosition", "uniform vec3 shadowLightPosition", BOTH, ALL_PROGRAMS, "View space position of the shadow light, the sun in daytime or the moon at night."),
+    ("upPosition", "uniform vec3 upPosition", BOTH, ALL_PROGRAMS, "View space direction of up, with a length of 100."),
+    ("cameraPosition", "uniform vec3 cameraPosition", BOTH, ALL_PROGRAMS, "World space position of the camera."),
+    ("previousCameraPosition", "uniform vec3 previousCameraPosition", BOTH, ALL_PROGRAMS, "`cameraPosition` in the last frame."),
+    ("cameraPositionInt", "uniform ivec3 cameraPositionInt", IRIS, ALL_PROGRAMS, "Integer part of `cameraPosition`, for better precision far from world origin."),
+    ("cameraPositionFract", "uniform vec3 cameraPositionFract", IRIS, ALL_PROGRAMS, "Fractional part of `cameraPosition`, for better precision far from world origin."),
+    ("previousCameraPositionInt", "uniform ivec3 previousCameraPositionInt", IRIS, ALL_PROGRAMS, "Integer part of `previousCameraPosition`."),
+    ("previousCameraPositionFract", "uniform vec3 previousCameraPositionFract", IRIS, ALL_PROGRAMS, "Fractional part of `previousCameraPosition`."),
+    ("chunkOffset", "uniform vec3 chunkOffset", BOTH, &["gbuffers_*", "shadow*", "dh_*"], "Offset of the current chunk, added to `vaPosition` to get the model space position."),
+    // Matrices
+    ("gbufferModelView", "uniform mat4 gbufferModelView", BOTH, ALL_PROGRAMS, "Transforms player space to view space, the modelview matrix after camera rotation."),
+    ("gbufferModelViewInverse", "uniform mat4 gbufferModelViewInverse", BOTH, ALL_PROGRAMS, "Transforms view space to player space."),
+    ("gbufferPreviousModelView", "uniform mat4 gbufferPreviousModelView", BOTH, ALL_PROGRAMS, "`gbufferModelView` in the last frame."),
+    ("gbufferProjection", "uniform mat4 gbufferProjection", BOTH, ALL_PROGRAMS, "Transforms view space to clip space, the projection matrix of gbuffers programs."),
+    ("gbufferProjectionInverse", "uniform mat4 gbufferProjectionInverse", BOTH, ALL_PROGRAMS, "Transforms clip space to view space."),
+    ("gbufferPreviousProjection", "uniform mat4 gbufferPreviousProjection", BOTH, ALL_PROGRAMS, "`gbufferProjection` in the last frame."),
+    ("shadowProjection", "uniform mat4 shadowProjection", BOTH, ALL_PROGRAMS, "Transforms shadow view space to shadow clip space."),
+    ("shadowProjectionInverse", "uniform mat4 shadowProjectionInverse", BOTH, ALL_PROGRAMS, "Transforms shadow clip space to shadow view space."),
+    ("shadowModelView", "uniform mat4 shadowModelView", BOTH, ALL_PROGRAMS, "Transforms player space to shadow view space."),
+    ("shadowModelViewInverse", "uniform mat4 shadowModelViewInverse", BOTH, ALL_PROGRAMS, "Transforms shadow view space to player space."),
+    ("modelViewMatrix", "uniform mat4 modelViewMatrix", BOTH, ALL_PROGRAMS, "Core profile replacement of `gl_ModelViewMatrix`."),
+    ("modelViewMatrixInverse", "uniform mat4 modelViewMatrixInverse", BOTH, ALL_PROGRAMS, "Core profile replacement of `gl_ModelViewMatrixInverse`."),
+    ("projectionMatrix", "uniform mat4 projectionMatrix", BOTH, ALL_PROGRAMS, "Core profile replacement of `gl_ProjectionMatrix`."),
+    ("projectionMatrixInverse", "uniform mat4 projectionMatrixInverse", BOTH, ALL_PROGRAMS, "Core profile replacement of `gl_ProjectionMatrixInverse`."),
+    ("textureMatrix", "uniform mat4 textureMatrix", BOTH, ALL_PROGRAMS, "Core profile replacement of `gl_TextureMatrix[0]`."),
+    ("normalMatrix", "uniform mat3 normalMatrix", BOTH, ALL_PROGRAMS, "Core profile replacement of `gl_NormalMatrix`."),
+    ("dhNearPlane", "uniform float dhNearPlane", IRIS, ALL_PROGRAMS, "Near plane distance of Distant Horizons terrain."),
+    ("dhFarPlane", "uniform float dhFarPlane", IRIS, ALL_PROGRAMS, "Far plane distance of Distant Horizons terrain."),
+    ("dhRenderDistance", "uniform int dhRenderDistance", IRIS, ALL_PROGRAMS, "Render distance of Distant Horizons in blocks."),
+    ("dhProjection", "uniform mat4 dhProjection", IRIS, ALL_PROGRAMS, "Projection matrix of Distant Horizons terrain."),
+    ("dhProjectionInverse", "uniform mat4 dhProjectionInverse", IRIS, ALL_PROGRAMS, "Inverse of `dhProjection`."),
+    ("dhPreviousProjection", "uniform mat4 dhPreviousProjection", IRIS, ALL_PROGRAMS, "`dhProjection` in the last frame."),
+    // Screen
+    ("viewWidth", "uniform float viewWidth", BOTH, ALL_PROGRAMS, "Width of the render targets in pixels."),
+    ("viewHeight", "uniform float viewHeight", BOTH, ALL_PROGRAMS, "Height of the render targets in pixels."),
+    ("aspectRatio", "uniform float aspectRatio", BOTH, ALL_PROGRAMS, "`viewWidth / viewHeight`."),
+    ("near", "uniform float near", BOTH, ALL_PROGRAMS, "Near plane distance, 0.05."),
+    ("far", "uniform float far", BOTH, ALL_PROGRAMS, "Render distance in blocks."),
+    ("centerDepthSmooth", "uniform float centerDepthSmooth", BOTH, ALL_PROGRAMS, "Depth at the screen center smoothed over time, configured by `centerDepthHalflife`."),
+    // Rendering states
+    ("renderStage", "uniform int renderStage", BOTH, &["gbuffers_*", "shadow*"], "Current render stage of geometries, compare with `MC_RENDER_STAGE_*` macros."),
+    ("entityId", "uniform int entityId", BOTH, &["gbuffers_entities*", "gbuffers_lightning", "shadow"], "Entity id of the entity being rendered, mapped by `entity.properties`."),
+    ("blockEntityId", "uniform int blockEntityId", BOTH, &["gbuffers_block*", "shadow"], "Block id of the block entity being rendered, mapped by `block.properties`."),
+    ("entityColor", "uniform vec4 entityColor", BOTH, &["gbuffers_entities*", "gbuffers_lightning", "shadow"], "Overlay color of the entity being rendered, red when hurt and white when creepers are about to explode."),
+    ("blendFunc", "uniform ivec4 blendFunc", BOTH, &["gbuffers_*", "shadow*"], "Blend function of the current geometry, as source color, destination color, source alpha and destination alpha factors."),
+    ("instanceId", "uniform int instanceId", OPTIFINE, ALL_PROGRAMS, "Index of the current instance, when `instance` is set in `shaders.properties`."),
+    ("atlasSize", "uniform ivec2 atlasSize", BOTH, &["gbuffers_*", "shadow*"], "Size of the texture atlas in pixels, 0 if the bound texture is not an atlas."),
+    ("spriteBounds", "uniform vec4 spriteBounds", OPTIFINE, &["gbuffers_*", "shadow*"], "Bounds of the current sprite in the atlas, as minimum uv in `xy` and maximum uv in `zw`."),
+    ("terrainTextureSize", "uniform ivec2 terrainTextureSize", OPTIFINE, ALL_PROGRAMS, "Size of the block atlas in pixels."),
+    ("terrainIconSize", "uniform int terrainIconSize", OPTIFINE, ALL_PROGRAMS, "Size of a sprite in the block atlas in pixels."),
+    ("alphaTestRef", "uniform float alphaTestRef", BOTH, &["gbuffers_*", "shadow*"], "Reference value of alpha test, fragments with alpha below it should be discarded."),
+    // Samplers
+    ("gtexture", "uniform sampler2D gtexture", BOTH, &["gbuffers_*", "shadow*"], "Texture of the current geometry, like the block atlas or an entity texture."),
+    ("texture", "uniform sampler2D texture", BOTH, &["gbuffers_*", "shadow*"], "Texture of the current geometry, same as `gtexture`. Conflicts with the `texture` function in GLSL 1.30+."),
+    ("tex", "uniform sampler2D tex", BOTH, &["gbuffers_*", "shadow*"], "Texture of the current geometry, same as `gtexture`."),
+    ("lightmap", "uniform sampler2D lightmap", BOTH, &["gbuffers_*", "shadow*"], "Vanilla lightmap, sampled with the lightmap coordinate."),
+    ("normals", "uniform sampler2D normals", BOTH, &["gbuffers_*", "shadow*"], "Normal map of the current geometry, from `_n` textures of the resource pack."),
+    ("specular", "uniform sampler2D specular", BOTH, &["gbuffers_*", "shadow*"], "Specular map of the current geometry, from `_s` textures of the resource pack."),
+    ("shadow", "uniform sampler2D shadow", BOTH, ALL_PROGRAMS, "Shadow map depth, same as `shadowtex1` if `watershadow` is declared, otherwise `shadowtex0`."),
+    ("watershadow", "uniform sampler2D watershadow", BOTH, ALL_PROGRAMS, "Shadow map depth including translucent geometries, same as `shadowtex0`."),
+    ("shadowtex0", "uniform sampler2D shadowtex0", BOTH, ALL_PROGRAMS, "Shadow map depth including translucent geometries."),
+    ("shadowtex1", "uniform sampler2D shadowtex1", BOTH, ALL_PROGRAMS, "Shadow map depth of opaque geometries only."),
+    ("shadowcolor0", "uniform sampler2D shadowcolor0", BOTH, ALL_PROGRAMS, "Shadow color buffer 0."),
+    ("shadowcolor1", "uniform sampler2D shadowcolor1", BOTH, ALL_PROGRAMS, "Shadow color buffer 1."),
+    ("shadowcolor", "uniform sampler2D shadowcolor", BOTH, ALL_PROGRAMS, "Shadow color buffer 0, same as `shadowcolor0`."),
+    ("depthtex0", "uniform sampler2D depthtex0", BOTH, ALL_PROGRAMS, "Depth buffer including all geometries."),
+    ("depthtex1", "uniform sampler2D depthtex1", BOTH, ALL_PROGRAMS, "Depth buffer without translucent geometries."),
+    ("depthtex2", "uniform sampler2D depthtex2", BOTH, ALL_PROGRAMS, "Depth buffer without translucent geometries and hand."),
+    ("dhDepthTex0", "uniform sampler2D dhDepthTex0", IRIS, ALL_PROGRAMS, "Depth buffer of all Distant Horizons terrain."),
+    ("dhDepthTex1", "uniform sampler2D dhDepthTex1", IRIS, ALL_PROGRAMS, "Depth buffer of opaque Distant Horizons terrain."),
+    ("noisetex", "uniform sampler2D noisetex", BOTH, ALL_PROGRAMS, "Noise texture, configured by `texture.noise` or `noiseTextureResolution`."),
+    ("gcolor", "uniform sampler2D gcolor", BOTH, ALL_PROGRAMS, "Color buffer 0, same as `colortex0`."),
+    ("gdepth", "uniform sampler2D gdepth", BOTH, ALL_PROGRAMS, "Color buffer 1, same as `colortex1`."),
+    ("gnormal", "uniform sampler2D gnormal", BOTH, ALL_PROGRAMS, "Color buffer 2, same as `colortex2`."),
+    ("composite", "uniform sampler2D composite", BOTH, ALL_PROGRAMS, "Color buffer 3, same as `colortex3`."),
+    ("gaux1", "uniform sampler2D gaux1", BOTH, ALL_PROGRAMS, "Color buffer 4, same as `colortex4`."),
+    ("gaux2", "uniform sampler2D gaux2", BOTH, ALL_PROGRAMS, "Color buffer 5, same as `colortex5`."),
+    ("gaux3", "uniform sampler2D gaux3", BOTH, ALL_PROGRAMS, "Color buffer 6, same as `colortex6`."),
+    ("gaux4", "uniform sampler2D gaux4", BOTH, ALL_PROGRAMS, "Color buffer 7, same as `colortex7`."),
+    ("colortex0", "uniform sampler2D colortex0", BOTH, ALL_PROGRAMS, "Color buffer 0."),
+    ("colortex1", "uniform sampler2D colortex1", BOTH, ALL_PROGRAMS, "Color buffer 1."),
+    ("colortex2", "uniform sampler2D colortex2", BOTH, ALL_PROGRAMS, "Color buffer 2."),
+    ("colortex3", "uniform sampler2D colortex3", BOTH, ALL_PROGRAMS, "Color buffer 3."),
+    ("colortex4", "uniform sampler2D colortex4", BOTH, ALL_PROGRAMS, "Color buffer 4."),
+    ("colortex5", "uniform sampler2D colortex5", BOTH, ALL_PROGRAMS, "Color buffer 5."),
+    ("colortex6", "uniform sampler2D colortex6", BOTH, ALL_PROGRAMS, "Color buffer 6."),
+    ("colortex7", "uniform sampler2D colortex7", BOTH, ALL_PROGRAMS, "Color buffer 7."),
+    ("colortex8", "uniform sampler2D colortex8", BOTH, ALL_PROGRAMS, "Color buffer 8."),
+    ("colortex9", "uniform sampler2D colortex9", BOTH, ALL_PROGRAMS, "Color buffer 9."),
+    ("colortex10", "uniform sampler2D colortex10", BOTH, ALL_PROGRAMS, "Color buffer 10."),
+    ("colortex11", "uniform sampler2D colortex11", BOTH, ALL_PROGRAMS, "Color buffer 11."),
+    ("colortex12", "uniform sampler2D colortex12", BOTH, ALL_PROGRAMS, "Color buffer 12."),
+    ("colortex13", "uniform sampler2D colortex13", BOTH, ALL_PROGRAMS, "Color buffer 13."),
+    ("colortex14", "uniform sampler2D colortex14", BOTH, ALL_PROGRAMS, "Color buffer 14."),
+    ("colortex15", "uniform sampler2D colortex15", BOTH, ALL_PROGRAMS, "Color buffer 15."),
+];
+
+/// Vertex attributes provided by shader loaders, as (name, declaration, loaders, programs, description)
+#[rustfmt::skip]
+pub(super) static ATTRIBUTES: &[UniformRow] = &[
+    ("mc_Entity", "in vec4 mc_Entity", BOTH, &["gbuffers_*", "shadow*"], "Block id of the current vertex in `x`, mapped by `block.properties`. -1 if not mapped."),
+    ("mc_midTexCoord", "in vec2 mc_midTexCoord", BOTH, &["gbuffers_*", "shadow*"], "Texture coordinate of the center of the current sprite."),
+    ("at_tangent", "in vec4 at_tangent", BOTH, &["gbuffers_*", "shadow*"], "Model space tangent of the current vertex, with handedness in `w`."),
+    ("at_velocity", "in vec3 at_velocity", IRIS, &["gbuffers_*", "shadow*"], "Movement of the current vertex since the last frame, in model space."),
+    ("at_midBlock", "in vec4 at_midBlock", BOTH, &["gbuffers_*", "shadow*"], "Offset from the current vertex to the block center in 1/64 blocks in `xyz`. Iris also stores the block light level in `w`."),
+    ("vaPosition", "in vec3 vaPosition", BOTH, ALL_PROGRAMS, "Core profile vertex position, relative to `chunkOffset` in terrain programs."),
+    ("vaColor", "in vec4 vaColor", BOTH, ALL_PROGRAMS, "Core profile vertex color."),
+    ("vaUV0", "in vec2 vaUV0", BOTH, ALL_PROGRAMS, "Core profile texture coordinate."),
+    ("vaUV1", "in ivec2 vaUV1", BOTH, ALL_PROGRAMS, "Core profile overlay coordinate."),
+    ("vaUV2", "in ivec2 vaUV2", BOTH, ALL_PROGRAMS, "Core profile lightmap coordinate, from 0 to 240."),
+    ("vaNormal", "in vec3 vaNormal", BOTH, ALL_PROGRAMS, "Core profile vertex normal."),
+];
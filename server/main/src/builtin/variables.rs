@@ -0,0 +1,56 @@
+use super::*;
+
+/// Built-in variables and constants, as (name, declaration, since version, profile, stages, description)
+#[rustfmt::skip]
+pub(super) static VARIABLES: &[VariableRow] = &[
+    // Vertex shader variables
+    ("gl_VertexID", "in int gl_VertexID", 130, ANY, VERTEX, "Index of the current vertex."),
+    ("gl_InstanceID", "in int gl_InstanceID", 140, ANY, VERTEX, "Index of the current instance in an instanced draw call."),
+    ("gl_Position", "out vec4 gl_Position", 110, ANY, VERTEX_GEOMETRY, "Clip space position of the current vertex."),
+    ("gl_PointSize", "out float gl_PointSize", 110, ANY, VERTEX_GEOMETRY, "Size of the point to be rasterized, in pixels."),
+    ("gl_ClipDistance", "out float gl_ClipDistance[]", 130, ANY, VERTEX_GEOMETRY, "Distances to the user clip planes, primitives are clipped where the distance is negative."),
+    ("gl_Vertex", "attribute vec4 gl_Vertex", 110, COMPATIBILITY, VERTEX, "Model space position of the current vertex."),
+    ("gl_Normal", "attribute vec3 gl_Normal", 110, COMPATIBILITY, VERTEX, "Model space normal of the current vertex."),
+    ("gl_Color", "attribute vec4 gl_Color", 110, COMPATIBILITY, VERTEX_FRAGMENT, "Vertex color attribute in vertex shaders. In fragment shaders, the interpolated `gl_FrontColor` or `gl_BackColor`."),
+    ("gl_MultiTexCoord0", "attribute vec4 gl_MultiTexCoord0", 110, COMPATIBILITY, VERTEX, "Texture coordinate of the current vertex in the block or item atlas."),
+    ("gl_MultiTexCoord1", "attribute vec4 gl_MultiTexCoord1", 110, COMPATIBILITY, VERTEX, "Lightmap coordinate of the current vertex, from 0 to 240 in vanilla."),
+    ("gl_MultiTexCoord2", "attribute vec4 gl_MultiTexCoord2", 110, COMPATIBILITY, VERTEX, "Lightmap coordinate of the current vertex, same as `gl_MultiTexCoord1`."),
+    ("gl_FrontColor", "varying vec4 gl_FrontColor", 110, COMPATIBILITY, VERTEX_GEOMETRY, "Color passed to the fragment shader for front facing primitives."),
+    ("gl_BackColor", "varying vec4 gl_BackColor", 110, COMPATIBILITY, VERTEX_GEOMETRY, "Color passed to the fragment shader for back facing primitives."),
+    ("gl_TexCoord", "varying vec4 gl_TexCoord[]", 110, COMPATIBILITY, ALL, "Texture coordinates passed from the vertex shader to the fragment shader."),
+    ("gl_FogFragCoord", "varying float gl_FogFragCoord", 110, COMPATIBILITY, ALL, "Fog coordinate passed from the vertex shader to the fragment shader."),
+    // Compatibility profile uniforms
+    ("gl_ModelViewMatrix", "uniform mat4 gl_ModelViewMatrix", 110, COMPATIBILITY, ALL, "Transforms model space to view space."),
+    ("gl_ProjectionMatrix", "uniform mat4 gl_ProjectionMatrix", 110, COMPATIBILITY, ALL, "Transforms view space to clip space."),
+    ("gl_ModelViewProjectionMatrix", "uniform mat4 gl_ModelViewProjectionMatrix", 110, COMPATIBILITY, ALL, "Transforms model space to clip space, same as `gl_ProjectionMatrix * gl_ModelViewMatrix`."),
+    ("gl_TextureMatrix", "uniform mat4 gl_TextureMatrix[]", 110, COMPATIBILITY, ALL, "Texture coordinate transforms, `gl_TextureMatrix[0]` for `gl_MultiTexCoord0` and `gl_TextureMatrix[1]` for the lightmap coordinate."),
+    ("gl_NormalMatrix", "uniform mat3 gl_NormalMatrix", 110, COMPATIBILITY, ALL, "Transforms model space normals to view space."),
+    ("gl_ModelViewMatrixInverse", "uniform mat4 gl_ModelViewMatrixInverse", 110, COMPATIBILITY, ALL, "Transforms view space to model space."),
+    ("gl_ProjectionMatrixInverse", "uniform mat4 gl_ProjectionMatrixInverse", 110, COMPATIBILITY, ALL, "Transforms clip space to view space."),
+    ("gl_Fog", "uniform gl_FogParameters gl_Fog", 110, COMPATIBILITY, ALL, "Fixed function fog parameters, with members `color`, `density`, `start`, `end` and `scale`."),
+    // Fragment shader variables
+    ("gl_FragCoord", "in vec4 gl_FragCoord", 110, ANY, FRAGMENT, "Window relative coordinate of the current fragment, with depth in `z` and `1 / w` in `w`."),
+    ("gl_FrontFacing", "in bool gl_FrontFacing", 110, ANY, FRAGMENT, "Whether the current fragment belongs to a front facing primitive."),
+    ("gl_PointCoord", "in vec2 gl_PointCoord", 110, ANY, FRAGMENT, "Coordinate of the current fragment inside a point primitive, from 0.0 to 1.0."),
+    ("gl_PrimitiveID", "in int gl_PrimitiveID", 150, ANY, GEOMETRY_FRAGMENT, "Index of the current primitive in the draw call."),
+    ("gl_SampleID", "in int gl_SampleID", 400, ANY, FRAGMENT, "Index of the sample currently being processed."),
+    ("gl_FragDepth", "out float gl_FragDepth", 110, ANY, FRAGMENT, "Depth of the current fragment. Uses `gl_FragCoord.z` if never written."),
+    ("gl_FragColor", "out vec4 gl_FragColor", 110, COMPATIBILITY, FRAGMENT, "Color written to all draw buffers. Can not be used together with `gl_FragData`."),
+    ("gl_FragData", "out vec4 gl_FragData[gl_MaxDrawBuffers]", 110, COMPATIBILITY, FRAGMENT, "Colors written to each draw buffer, listed in `DRAWBUFFERS` or `RENDERTARGETS` of the shader."),
+    // Geometry shader variables
+    ("gl_in", "in gl_PerVertex { vec4 gl_Position; float gl_PointSize; float gl_ClipDistance[]; } gl_in[]", 150, ANY, GEOMETRY, "Outputs of the vertex shader for each vertex of the input primitive."),
+    ("gl_PrimitiveIDIn", "in int gl_PrimitiveIDIn", 150, ANY, GEOMETRY, "Index of the input primitive in the draw call."),
+    ("gl_InvocationID", "in int gl_InvocationID", 400, ANY, GEOMETRY, "Index of the current geometry shader invocation."),
+    ("gl_Layer", "out int gl_Layer", 150, ANY, GEOMETRY, "Layer of a layered framebuffer to render the primitive to."),
+    ("gl_ViewportIndex", "out int gl_ViewportIndex", 410, ANY, GEOMETRY, "Viewport to render the primitive to."),
+    // Compute shader variables
+    ("gl_NumWorkGroups", "in uvec3 gl_NumWorkGroups", 430, ANY, COMPUTE, "Number of work groups in the dispatch."),
+    ("gl_WorkGroupSize", "const uvec3 gl_WorkGroupSize", 430, ANY, COMPUTE, "Local size of the work group, declared with `layout(local_size_x = ...) in;`."),
+    ("gl_WorkGroupID", "in uvec3 gl_WorkGroupID", 430, ANY, COMPUTE, "Index of the current work group."),
+    ("gl_LocalInvocationID", "in uvec3 gl_LocalInvocationID", 430, ANY, COMPUTE, "Index of the current invocation in its work group."),
+    ("gl_GlobalInvocationID", "in uvec3 gl_GlobalInvocationID", 430, ANY, COMPUTE, "Index of the current invocation in the dispatch, `gl_WorkGroupID * gl_WorkGroupSize + gl_LocalInvocationID`."),
+    ("gl_LocalInvocationIndex", "in uint gl_LocalInvocationIndex", 430, ANY, COMPUTE, "Flattened index of `gl_LocalInvocationID`."),
+    // Constants
+    ("gl_MaxDrawBuffers", "const int gl_MaxDrawBuffers", 110, ANY, ALL, "Maximum number of draw buffers, at least 8."),
+    ("gl_MaxTextureCoords", "const int gl_MaxTextureCoords", 110, COMPATIBILITY, ALL, "Maximum number of texture coordinate sets, at least 8."),
+];
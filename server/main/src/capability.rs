@@ -1,5 +1,7 @@
 use tower_lsp::lsp_types::*;
 
+use crate::tree_parser::{TOKEN_MODIFIERS, TOKEN_TYPES};
+
 pub struct ServerCapabilitiesFactroy;
 
 impl ServerCapabilitiesFactroy {
@@ -9,10 +11,51 @@ impl ServerCapabilitiesFactroy {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::INCREMENTAL)),
                 definition_provider: Some(OneOf::Left(true)),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec!["\"".to_owned(), "/".to_owned(), "<".to_owned()]),
+                    ..Default::default()
+                }),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_owned(), ",".to_owned()]),
+                    retrigger_characters: Some(vec![")".to_owned()]),
+                    work_done_progress_options: WorkDoneProgressOptions { work_done_progress: None },
+                }),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(false),
+                }),
                 references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions { work_done_progress: None },
+                })),
                 document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                document_highlight_provider: Some(OneOf::Left(true)),
+                selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
+                    work_done_progress_options: WorkDoneProgressOptions { work_done_progress: None },
+                    legend: SemanticTokensLegend {
+                        token_types: TOKEN_TYPES.to_vec(),
+                        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+                    },
+                    range: Some(false),
+                    full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                })),
                 execute_command_provider: Some(ExecuteCommandOptions {
-                    commands: vec!["virtualMerge".to_owned()],
+                    commands: vec![
+                        "virtualMerge".to_owned(),
+                        "renderTargets".to_owned(),
+                        "programFallbacks".to_owned(),
+                        "archiveFiles".to_owned(),
+                        "archiveContent".to_owned(),
+                    ],
                     ..Default::default()
                 }),
                 workspace: Some(WorkspaceServerCapabilities {
@@ -0,0 +1,20 @@
+use super::*;
+
+impl Command for ArchiveContent {
+    /// Content of a file in zipped shader packs, for clients to show it as a read-only document.
+    fn run(&self, arguments: &[Value], server_data: &MutexGuard<ServerData>) -> Result<Option<Value>> {
+        let file_path = match arguments.first() {
+            Some(value) => file_path_argument(value)?,
+            None => return Err(LanguageServerError::invalid_argument_error()),
+        };
+
+        server_data
+            .shader_packs()
+            .borrow()
+            .iter()
+            .filter(|shader_pack| file_path.starts_with(&shader_pack.path))
+            .find_map(|shader_pack| shader_pack.archive.as_ref()?.read_file(&file_path).cloned())
+            .map(|content| Some(Value::String(content)))
+            .ok_or_else(LanguageServerError::not_archive_file_error)
+    }
+}
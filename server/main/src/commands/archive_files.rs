@@ -0,0 +1,35 @@
+use serde_json::json;
+
+use crate::file::path_to_url;
+
+use super::*;
+
+impl Command for ArchiveFiles {
+    /// Files in zipped shader packs, with urls to open them as read-only documents.
+    fn run(&self, arguments: &[Value], server_data: &MutexGuard<ServerData>) -> Result<Option<Value>> {
+        let shader_packs = selected_shader_packs(arguments, server_data)?;
+
+        let result = shader_packs
+            .iter()
+            .filter_map(|shader_pack| {
+                let mut files = shader_pack.archive.as_ref()?.files().collect::<Vec<_>>();
+                files.sort_unstable();
+                let files = files
+                    .into_iter()
+                    .map(|file_path| {
+                        json!({
+                            "path": file_path.strip_prefix(&shader_pack.path).unwrap_or(file_path),
+                            "uri": path_to_url(file_path),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                Some(json!({
+                    "path": shader_pack.path,
+                    "files": files,
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Some(Value::Array(result)))
+    }
+}
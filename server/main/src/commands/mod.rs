@@ -1,14 +1,57 @@
+use std::path::PathBuf;
+#[cfg(target_os = "windows")]
+use std::path::MAIN_SEPARATOR_STR;
+use std::rc::Rc;
 use std::sync::MutexGuard;
 
 use serde_json::Value;
 use tower_lsp::jsonrpc::Result;
 
+use crate::file::ShaderPack;
 use crate::server::{LanguageServerError, ServerData};
 
+mod archive_content;
+mod archive_files;
+mod program_fallbacks;
+mod render_targets;
 mod virtual_merge;
 
 pub struct VirtualMerge;
 
+pub struct RenderTargets;
+
+pub struct ProgramFallbacks;
+
+pub struct ArchiveFiles;
+
+pub struct ArchiveContent;
+
 pub trait Command {
     fn run(&self, arguments: &[Value], server_data: &MutexGuard<ServerData>) -> Result<Option<Value>>;
 }
+
+/// File path from a command argument, in the form of url path.
+fn file_path_argument(value: &Value) -> Result<PathBuf> {
+    match value.as_str() {
+        #[cfg(target_os = "windows")]
+        Some(uri) => Ok(PathBuf::from(uri.strip_prefix('/').unwrap_or(uri).replace('/', MAIN_SEPARATOR_STR))),
+        #[cfg(not(target_os = "windows"))]
+        Some(uri) => Ok(PathBuf::from(uri)),
+        None => Err(LanguageServerError::invalid_argument_error()),
+    }
+}
+
+/// Shader packs sorted by path. If a file path is given as the first argument, only the pack containing it is returned.
+fn selected_shader_packs(arguments: &[Value], server_data: &MutexGuard<ServerData>) -> Result<Vec<Rc<ShaderPack>>> {
+    let file_path = arguments.first().map(file_path_argument).transpose()?;
+
+    let mut shader_packs = server_data
+        .shader_packs()
+        .borrow()
+        .iter()
+        .filter(|shader_pack| file_path.as_ref().is_none_or(|file_path| file_path.starts_with(&shader_pack.path)))
+        .cloned()
+        .collect::<Vec<_>>();
+    shader_packs.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+    Ok(shader_packs)
+}
@@ -0,0 +1,26 @@
+use serde_json::json;
+
+use crate::pipeline::FallbackTree;
+
+use super::*;
+
+impl Command for ProgramFallbacks {
+    /// Programs actually used for each known program after falling back. If a file path is given, only the pack containing it will be analyzed.
+    fn run(&self, arguments: &[Value], server_data: &MutexGuard<ServerData>) -> Result<Option<Value>> {
+        let shader_packs = selected_shader_packs(arguments, server_data)?;
+        let workspace_files = server_data.workspace_files().borrow();
+        let programs = server_data.programs().borrow();
+
+        let result = shader_packs
+            .iter()
+            .map(|shader_pack| {
+                json!({
+                    "path": shader_pack.path,
+                    "fallbacks": FallbackTree::build(shader_pack, &programs, workspace_files.iter()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Some(Value::Array(result)))
+    }
+}
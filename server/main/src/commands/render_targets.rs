@@ -0,0 +1,26 @@
+use serde_json::json;
+
+use crate::pipeline::RenderPipeline;
+
+use super::*;
+
+impl Command for RenderTargets {
+    /// Render target graph of shader packs. If a file path is given, only the pack containing it will be analyzed.
+    fn run(&self, arguments: &[Value], server_data: &MutexGuard<ServerData>) -> Result<Option<Value>> {
+        let shader_packs = selected_shader_packs(arguments, server_data)?;
+        let workspace_files = server_data.workspace_files().borrow();
+        let programs = server_data.programs().borrow();
+
+        let result = shader_packs
+            .iter()
+            .map(|shader_pack| {
+                json!({
+                    "path": shader_pack.path,
+                    "pipelines": RenderPipeline::build(shader_pack, &programs, workspace_files.iter()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Some(Value::Array(result)))
+    }
+}
@@ -1,4 +1,6 @@
-use std::path::{PathBuf, MAIN_SEPARATOR_STR};
+use std::path::PathBuf;
+#[cfg(target_os = "windows")]
+use std::path::MAIN_SEPARATOR_STR;
 
 use hashbrown::HashMap;
 
@@ -8,7 +10,7 @@ use super::*;
 
 impl Command for VirtualMerge {
     fn run(&self, arguments: &[Value], server_data: &MutexGuard<ServerData>) -> Result<Option<Value>> {
-        let value = arguments.get(0).unwrap();
+        let value = arguments.first().unwrap();
         let file_uri = match value.as_str() {
             Some(uri) => uri,
             None => return Err(LanguageServerError::invalid_argument_error()),
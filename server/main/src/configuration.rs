@@ -1,8 +1,12 @@
 use hashbrown::HashSet;
-use serde::Deserialize;
+use logging::warn;
+use serde::{Deserialize, Deserializer};
 use serde_json::{from_value, Value};
 use tower_lsp::lsp_types::*;
 
+use crate::pipeline::ProgramInfo;
+use crate::tree_parser::FormatStyle;
+
 #[derive(Deserialize)]
 pub struct Configuration {
     #[serde(alias = "logLevel")]
@@ -11,6 +15,34 @@ pub struct Configuration {
     pub extra_extension: HashSet<String>,
     #[serde(alias = "tempLint")]
     pub temp_lint: bool,
+    #[serde(alias = "extraPrograms", default, deserialize_with = "valid_entries")]
+    pub extra_programs: Vec<ProgramInfo>,
+    #[serde(default, deserialize_with = "or_default")]
+    pub format: FormatStyle,
+}
+
+/// Entries of a user-written array that deserialize, invalid ones are logged and skipped
+fn valid_entries<'de, D: Deserializer<'de>, T: for<'a> Deserialize<'a>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    let entries = Vec::<Value>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| match from_value(entry.clone()) {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                warn!("Skipped invalid configuration entry"; "entry" => entry.to_string(), "error" => err.to_string());
+                None
+            }
+        })
+        .collect())
+}
+
+/// A user-written value, falling back to default if it is invalid
+pub fn or_default<'de, D: Deserializer<'de>, T: for<'a> Deserialize<'a> + Default>(deserializer: D) -> Result<T, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    Ok(from_value(value.clone()).unwrap_or_else(|err| {
+        warn!("Used default for invalid configuration value"; "value" => value.to_string(), "error" => err.to_string());
+        T::default()
+    }))
 }
 
 impl Configuration {
@@ -40,6 +72,18 @@ impl Configuration {
                     glob_pattern: GlobPattern::String(folder_pattern),
                     kind: Some(WatchKind::Delete),
                 },
+                FileSystemWatcher {
+                    glob_pattern: GlobPattern::String("**/shaders/shaders.properties".to_owned()),
+                    kind: Some(WatchKind::all()),
+                },
+                FileSystemWatcher {
+                    glob_pattern: GlobPattern::String("**/shaders/dimension.properties".to_owned()),
+                    kind: Some(WatchKind::all()),
+                },
+                FileSystemWatcher {
+                    glob_pattern: GlobPattern::String("**/*.zip".to_owned()),
+                    kind: Some(WatchKind::all()),
+                },
             ],
         };
         let will_rename_files = FileOperationRegistrationOptions {
@@ -15,16 +15,18 @@ lazy_static! {
             "glsl".to_owned(),
         ])
     };
-    pub static ref RE_BASIC_SHADERS: Regex = Regex::new(
-        r"^(dh_(terrain|water|shadow)|shadow|gbuffers_(armor_glint|basic|beaconbeam|block|clouds|damagedblock|entities|entities_glowing|hand|hand_water|line|skybasic|skytextured|spidereyes|terrain|textured|textured_lit|water|weather)).(vsh|gsh|fsh)|(final|(shadowcomp|prepare|deferred|composite)([1-9]\d?)?)(.vsh|.gsh|.fsh|(_[a-z])?.csh)$"
-    ).unwrap();
-    pub static ref COMMAND_LIST: HashMap<&'static str, Box<dyn Command + Sync + Send>> =
-        HashMap::from([("virtualMerge", Box::new(VirtualMerge {}) as Box<dyn Command + Sync + Send>)])
-    ;
+    pub static ref COMMAND_LIST: HashMap<&'static str, Box<dyn Command + Sync + Send>> = HashMap::from([
+        ("virtualMerge", Box::new(VirtualMerge {}) as Box<dyn Command + Sync + Send>),
+        ("renderTargets", Box::new(RenderTargets {}) as Box<dyn Command + Sync + Send>),
+        ("programFallbacks", Box::new(ProgramFallbacks {}) as Box<dyn Command + Sync + Send>),
+        ("archiveFiles", Box::new(ArchiveFiles {}) as Box<dyn Command + Sync + Send>),
+        ("archiveContent", Box::new(ArchiveContent {}) as Box<dyn Command + Sync + Send>),
+    ]);
     pub static ref RE_DIMENSION_FOLDER: Regex = Regex::new(r"^world-?\d+$").unwrap();
     pub static ref RE_MACRO_PARSER_MULTI_LINE: Regex = Regex::new(r#"(?m)^[ \f\t\v]*#\s*((include|moj_import)\s+[<"](.+)[>"]|line|version).?$"#).unwrap();
     pub static ref RE_MACRO_PARSER: Regex = Regex::new(r#"^\s*#\s*(include\s+"(.+)"|line|version)"#).unwrap();
     pub static ref RE_MACRO_PARSER_TEMP: Regex = Regex::new(r#"^\s*#\s*((include|moj_import)\s+[<"](.+)[>"]|line|version)"#).unwrap();
+    pub static ref RE_INCLUDE_PATH_PREFIX: Regex = Regex::new(r#"^\s*#\s*(include|moj_import)\s+["<]([^"<>]*)$"#).unwrap();
     pub static ref RE_MACRO_VERSION: Regex = Regex::new(r"^[ \f\t\v]*#\s*version[ \f\t\v]+(\d+)([ \f\t\v]+[a-z]+)?").unwrap();
     pub static ref RE_COMMENT: Regex = Regex::new(r"/[/*]|\*/|\\\r?$").unwrap();
     pub static ref OPENGL_CONTEXT: OpenGlContext = OpenGlContext::new();
@@ -41,6 +43,11 @@ lazy_static! {
     };
 }
 
+/// Url scheme of files inside zipped shader packs
+pub const ARCHIVE_SCHEME: &str = "mcshader-zip";
+/// Appended to archive path to form the virtual root of its files
+pub const ARCHIVE_SEPARATOR: &str = "!";
+
 pub const OPTIFINE_MACROS: &str = "#define MC_VERSION 11900
 #define MC_GL_VERSION 320
 #define MC_GLSL_VERSION 150
@@ -0,0 +1,138 @@
+use std::fs::File;
+use std::io::Read;
+
+use zip::ZipArchive;
+
+use super::*;
+
+/// Read-only content of a zipped shader pack, loaded into memory.
+///
+/// Files inside are addressed by virtual paths like `<path to pack.zip>!/shaders/final.fsh`,
+/// so they can be handled as normal files under the shader pack path.
+pub struct ShaderArchive {
+    files: HashMap<PathBuf, String>,
+}
+
+impl ShaderArchive {
+    /// Load the `shaders` folder in this archive. Returns the virtual shader pack path and the archive.
+    ///
+    /// The `shaders` folder might be at root or inside a folder named after the pack.
+    /// Only files with these extensions and `.properties` files are loaded.
+    pub fn load(archive_path: &Path, extensions: &HashSet<String>) -> Option<(PathBuf, Self)> {
+        let mut archive = match File::open(archive_path).map(ZipArchive::new) {
+            Ok(Ok(archive)) => archive,
+            _ => {
+                error!("Unable to read archive {}", archive_path.to_str().unwrap());
+                return None;
+            }
+        };
+
+        // Prefix of files in the top most `shaders` folder, like `shaders/` or `PackName/shaders/`
+        let shaders_folder = archive
+            .file_names()
+            .filter_map(|name| {
+                let (index, _) = name
+                    .match_indices("shaders/")
+                    .find(|(index, _)| *index == 0 || name.as_bytes()[index - 1] == b'/')?;
+                Some(name[..index + "shaders/".len()].to_owned())
+            })
+            .min_by_key(|prefix| prefix.len())?;
+
+        let root_path = archive_root(archive_path);
+        let virtual_path = |name: &str| {
+            name.split_terminator('/')
+                .fold(root_path.clone(), |path, component| path.join(component))
+        };
+
+        let mut files = HashMap::new();
+        for index in 0..archive.len() {
+            let mut file = match archive.by_index(index) {
+                Ok(file) if file.is_file() && file.name().starts_with(&shaders_folder) => file,
+                _ => continue,
+            };
+            // Textures and other binary files are skipped before decompressing them
+            let is_text_file = Path::new(file.name())
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| extension == "properties" || extensions.contains(extension));
+            if !is_text_file {
+                continue;
+            }
+            let file_path = virtual_path(file.name());
+            let mut content = String::new();
+            if file.read_to_string(&mut content).is_ok() {
+                files.insert(file_path, content);
+            }
+        }
+
+        let pack_path = virtual_path(&shaders_folder);
+        Some((pack_path, ShaderArchive { files }))
+    }
+
+    pub fn read_file(&self, file_path: &Path) -> Option<&String> {
+        self.files.get(file_path)
+    }
+
+    /// Paths of files directly inside this folder
+    pub fn list_folder<'a>(&'a self, folder: &'a Path) -> impl Iterator<Item = &'a PathBuf> {
+        self.files.keys().filter(move |file_path| file_path.parent() == Some(folder))
+    }
+
+    /// Paths of folders directly inside this folder
+    pub fn list_subfolders(&self, folder: &Path) -> Vec<PathBuf> {
+        let mut subfolders = self
+            .files
+            .keys()
+            .filter_map(|file_path| {
+                let relative_path = file_path.strip_prefix(folder).ok()?;
+                let mut components = relative_path.components();
+                let subfolder = components.next()?;
+                components.next().map(|_| folder.join(subfolder))
+            })
+            .collect::<Vec<_>>();
+        subfolders.sort_unstable();
+        subfolders.dedup();
+        subfolders
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.keys()
+    }
+}
+
+/// Virtual path of the root folder inside this archive, parent of all paths of its files
+pub fn archive_root(archive_path: &Path) -> PathBuf {
+    let mut root_path = archive_path.as_os_str().to_owned();
+    root_path.push(ARCHIVE_SEPARATOR);
+    PathBuf::from(root_path)
+}
+
+/// Whether this path points into a zipped shader pack
+pub fn is_archive_path(file_path: &Path) -> bool {
+    file_path.components().any(
+        |component| match component.as_os_str().to_str().and_then(|name| name.strip_suffix(ARCHIVE_SEPARATOR)) {
+            Some(name) => name.ends_with(".zip"),
+            None => false,
+        },
+    )
+}
+
+/// Convert a document url to its file path, urls of files in zipped shader packs included.
+pub fn url_to_path(url: &Url) -> PathBuf {
+    if url.scheme() == ARCHIVE_SCHEME {
+        let file_url = Url::parse(&("file:".to_owned() + &url.as_str()[ARCHIVE_SCHEME.len() + 1..])).unwrap();
+        file_url.to_file_path().unwrap()
+    } else {
+        url.to_file_path().unwrap()
+    }
+}
+
+/// Convert a file path to its document url, paths of files in zipped shader packs included.
+pub fn path_to_url(file_path: &Path) -> Url {
+    let file_url = Url::from_file_path(file_path).unwrap();
+    if is_archive_path(file_path) {
+        Url::parse(&(ARCHIVE_SCHEME.to_owned() + &file_url.as_str()["file".len()..])).unwrap()
+    } else {
+        file_url
+    }
+}
@@ -6,7 +6,7 @@ use std::{
     rc::Rc,
 };
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use itoa::Buffer;
 use logging::{error, warn};
 use regex::Matches;
@@ -14,11 +14,16 @@ use tower_lsp::lsp_types::*;
 use tree_sitter::{InputEdit, Parser, Point, Tree};
 
 use crate::constant::*;
+use crate::pipeline::ProgramRegistry;
+use crate::properties::{DimensionProperties, ShaderProperties};
 
+mod archive;
 mod compile_cache;
 mod temp_file;
 mod workspace_file;
 
+pub use archive::*;
+
 pub type IncludeInformation = (usize, usize, usize, Rc<PathBuf>, Rc<WorkspaceFile>);
 pub type ShaderData = (Rc<WorkspaceFile>, RefCell<Vec<Diagnostic>>);
 
@@ -29,7 +34,7 @@ enum CommentType {
     Multi,
 }
 
-fn include_path_join(root_path: &Path, curr_path: &Path, additional: &str) -> Result<PathBuf, &'static str> {
+pub fn include_path_join(root_path: &Path, curr_path: &Path, additional: &str) -> Result<PathBuf, &'static str> {
     let mut buffer: Vec<Component>;
     let additional = match additional.strip_prefix('/') {
         Some(path) => {
@@ -71,6 +76,14 @@ fn include_path_join(root_path: &Path, curr_path: &Path, additional: &str) -> Re
     Ok(PathBuf::from(resource))
 }
 
+/// `#moj_import <path>` looks for files in the `include` folder, path may be prefixed by a namespace like `minecraft:`
+pub fn moj_import_path(pack_path: &Path, path: &str) -> PathBuf {
+    let path = path.split_once(':').map_or(path, |(_, path)| path);
+    path.split('/')
+        .filter(|component| !component.is_empty())
+        .fold(pack_path.join("include"), |path, component| path.join(component))
+}
+
 fn push_line_macro(content: &mut String, line: usize, file_id: &str, file_name: &str) {
     content.push_str("#line ");
     content.push_str(Buffer::new().format(line));
@@ -205,8 +218,13 @@ pub trait ShaderFile {
     fn line_mapping(&self) -> &RefCell<Vec<usize>>;
     fn include_links(&self) -> Vec<DocumentLink>;
 
+    /// Read content of this file from disc
+    fn read_source(&self, file_path: &Path) -> Option<String> {
+        read_to_string(file_path).ok()
+    }
+
     fn update_from_disc(&self, parser: &mut Parser, file_path: &Path) -> bool {
-        if let Ok(content) = read_to_string(file_path) {
+        if let Some(content) = self.read_source(file_path) {
             *self.tree().borrow_mut() = parser.parse(&content, None).unwrap();
             *self.line_mapping().borrow_mut() = generate_line_mapping(&content);
             *self.content().borrow_mut() = content;
@@ -335,6 +353,62 @@ pub struct TempFile {
 pub struct ShaderPack {
     pub path: PathBuf,
     pub debug: bool,
+    /// Parsed `shaders.properties` of this pack
+    pub properties: RefCell<ShaderProperties>,
+    /// Dimension folders declared in `dimension.properties` of this pack
+    pub dimensions: RefCell<DimensionProperties>,
+    /// Diagnostics from render pipeline analysis of this pack
+    pub pipeline_diagnostics: RefCell<HashMap<PathBuf, Vec<Diagnostic>>>,
+    /// Content of a zipped shader pack. `None` for packs in folders
+    pub archive: Option<ShaderArchive>,
+}
+
+impl ShaderPack {
+    pub fn new(path: PathBuf, debug: bool) -> Self {
+        ShaderPack {
+            path,
+            debug,
+            properties: RefCell::new(ShaderProperties::default()),
+            dimensions: RefCell::new(DimensionProperties::default()),
+            pipeline_diagnostics: RefCell::new(HashMap::new()),
+            archive: None,
+        }
+    }
+
+    /// Read-only shader pack from a zip file, loading files with these extensions
+    pub fn from_archive(archive_path: &Path, extensions: &HashSet<String>) -> Option<Self> {
+        let (path, archive) = ShaderArchive::load(archive_path, extensions)?;
+        Some(ShaderPack {
+            archive: Some(archive),
+            ..ShaderPack::new(path, false)
+        })
+    }
+
+    /// Read a file in this pack, from archive for zipped packs.
+    pub fn read_file(&self, file_path: &Path) -> Option<String> {
+        match &self.archive {
+            Some(archive) => archive.read_file(file_path).cloned(),
+            None => read_to_string(file_path).ok(),
+        }
+    }
+
+    /// Files and folders directly inside a folder of this pack, from archive for zipped packs.
+    pub fn list_folder(&self, folder: &Path) -> (Vec<PathBuf>, Vec<PathBuf>) {
+        match &self.archive {
+            Some(archive) => (archive.list_folder(folder).cloned().collect(), archive.list_subfolders(folder)),
+            None => {
+                let (mut files, mut folders) = (vec![], vec![]);
+                for entry in folder.read_dir().into_iter().flatten().filter_map(|entry| entry.ok()) {
+                    match entry.file_type() {
+                        Ok(file_type) if file_type.is_dir() => folders.push(entry.path()),
+                        Ok(_) => files.push(entry.path()),
+                        Err(_) => {}
+                    }
+                }
+                (files, folders)
+            }
+        }
+    }
 }
 
 impl core::hash::Hash for ShaderPack {
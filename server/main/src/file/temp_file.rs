@@ -5,7 +5,7 @@ impl TempFile {
         &self.shader_pack
     }
 
-    pub fn new(parser: &mut Parser, file_path: &Path, content: String) -> Self {
+    pub fn new(parser: &mut Parser, programs: &ProgramRegistry, file_path: &Path, content: String) -> Self {
         warn!("Document not found in file system"; "path" => file_path.to_str().unwrap());
         let mut file_type = match file_path.extension() {
             Some(ext) if ext == "vsh" => gl::VERTEX_SHADER,
@@ -41,22 +41,31 @@ impl TempFile {
                 }
             }
             resource.push("shaders");
-            if file_type != gl::NONE {
+        }
+        let pack_path = PathBuf::from(resource);
+        let debug = pack_path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .is_some_and(|name| name == "debug");
+        let shader_pack = ShaderPack::new(pack_path, debug);
+
+        // Files not matching any program are considered as include files
+        if file_type != gl::INVALID_ENUM && file_type != gl::NONE {
+            shader_pack.update_dimensions();
+            let relative_path = file_path.strip_prefix(&shader_pack.path).unwrap().to_str().unwrap();
+            if programs.match_path(&shader_pack, relative_path).is_some() {
                 cache = Some(CompileCache::new());
+            } else {
+                file_type = gl::NONE;
             }
         }
 
         let tree = parser.parse(&content, None).unwrap();
         let line_mapping = generate_line_mapping(&content);
-        let pack_path = PathBuf::from(resource);
-        let debug = pack_path
-            .parent()
-            .and_then(|parent| parent.file_name())
-            .map_or(false, |name| name == "debug");
 
         let temp_file = TempFile {
             file_type: RefCell::new(file_type),
-            shader_pack: ShaderPack { path: pack_path, debug },
+            shader_pack,
             content: RefCell::new(content),
             version: RefCell::new(None),
             cache: RefCell::new(cache),
@@ -149,13 +158,8 @@ impl TempFile {
                         Ok(include_path) => including_files.push((line, start, end, include_path)),
                         Err(error) => error!("Unable to parse include link {}, error: {}", path, error),
                     },
-                    _ => {
-                        // If marco name is not include, it must be moj_import
-                        let additional_path = "include".to_owned() + MAIN_SEPARATOR_STR + path;
-                        let include_path = pack_path.join(additional_path);
-
-                        including_files.push((line, start, end, include_path));
-                    }
+                    // If marco name is not include, it must be moj_import
+                    _ => including_files.push((line, start, end, moj_import_path(pack_path, path))),
                 }
             } else {
                 end_in_comment(0, comment_matches, &mut in_comment, &mut comment_type);
@@ -312,10 +316,7 @@ impl TempFile {
                             }
                         },
                         // moj_import
-                        _ => {
-                            let additional_path = "include".to_owned() + MAIN_SEPARATOR_STR + include_path;
-                            pack_path.join(additional_path)
-                        }
+                        _ => moj_import_path(pack_path, include_path),
                     };
                     if Self::merge_temp(pack_path, &include_path, temp_content, version, file_id, depth + 1) {
                         push_line_macro(temp_content, line + 2, curr_file_id, file_name);
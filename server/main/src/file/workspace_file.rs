@@ -379,6 +379,14 @@ impl WorkspaceFile {
         shader_content.push('\n');
     }
 
+    /// Turn this shader into a file only used by includes, when its program is no longer known.
+    pub fn demote_shader(&self, update_list: &mut HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>) {
+        *self.file_type.borrow_mut() = gl::NONE;
+        *self.cache.borrow_mut() = None;
+        // Parent shaders are rebuilt from files including it, which no longer contain itself
+        self.update_shader_list(update_list, 0);
+    }
+
     pub fn clear(&self, parser: &mut Parser, file_path: &PathBuf, update_list: &mut HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>) {
         *self.file_type.borrow_mut() = gl::INVALID_ENUM;
         self.content.borrow_mut().clear();
@@ -406,6 +414,29 @@ impl WorkspaceFile {
             });
     }
 
+    /// Files in the include tree of this file (self included), in the order they first appear in merged shader.
+    pub fn include_tree(rc_self: &Rc<WorkspaceFile>, file_path: &Rc<PathBuf>) -> Vec<(Rc<PathBuf>, Rc<WorkspaceFile>)> {
+        let mut file_list = vec![(file_path.clone(), rc_self.clone())];
+        rc_self.collect_include_tree(&mut file_list, 0);
+        file_list
+    }
+
+    fn collect_include_tree(&self, file_list: &mut Vec<(Rc<PathBuf>, Rc<WorkspaceFile>)>, mut depth: u8) {
+        if depth < 10 {
+            depth += 1;
+            self.including_files
+                .borrow()
+                .iter()
+                .filter(|(_, _, _, _, include_file)| *include_file.file_type.borrow() != gl::INVALID_ENUM)
+                .for_each(|(_, _, _, include_path, include_file)| {
+                    if !file_list.iter().any(|(file_path, _)| file_path == include_path) {
+                        file_list.push((include_path.clone(), include_file.clone()));
+                        include_file.collect_include_tree(file_list, depth);
+                    }
+                });
+        }
+    }
+
     pub fn including_pathes(&self) -> HashMap<Rc<PathBuf>, Rc<WorkspaceFile>> {
         self.including_files()
             .borrow()
@@ -416,6 +447,11 @@ impl WorkspaceFile {
 }
 
 impl ShaderFile for WorkspaceFile {
+    /// Files in zipped shader packs are read from archive
+    fn read_source(&self, file_path: &Path) -> Option<String> {
+        self.shader_pack.read_file(file_path)
+    }
+
     fn file_type(&self) -> &RefCell<u32> {
         &self.file_type
     }
@@ -441,7 +477,7 @@ impl ShaderFile for WorkspaceFile {
             .borrow()
             .iter()
             .map(|(line, start, end, include_path, _)| {
-                let url = Url::from_file_path(include_path as &Path).unwrap();
+                let url = path_to_url(include_path);
                 DocumentLink {
                     range: Range {
                         start: Position {
@@ -1,5 +1,6 @@
 use tower_lsp::{LspService, Server};
 
+mod builtin;
 mod capability;
 mod commands;
 mod configuration;
@@ -7,6 +8,8 @@ mod constant;
 mod file;
 mod notification;
 mod opengl;
+mod pipeline;
+mod properties;
 mod server;
 mod tree_parser;
 
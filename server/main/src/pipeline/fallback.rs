@@ -0,0 +1,100 @@
+use hashbrown::HashSet;
+
+use super::*;
+
+/// A known program and the program actually used for it after falling back.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramFallback {
+    pub program: String,
+    pub stage: Option<PipelineStage>,
+    pub loaders: Vec<Loader>,
+    /// `None` if neither the program nor its fallbacks exist, so the program is disabled
+    pub used_program: Option<String>,
+    /// Shader files of the used program
+    pub files: Vec<Url>,
+}
+
+/// Fallbacks of all known programs in the base folder or a dimension folder.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FallbackTree {
+    /// Dimension folder name. `None` for programs in the base folder
+    pub dimension: Option<String>,
+    pub programs: Vec<ProgramFallback>,
+}
+
+impl FallbackTree {
+    /// Build fallback trees of the base folder and each dimension folder in this pack.
+    pub fn build<'a>(
+        shader_pack: &Rc<ShaderPack>, registry: &ProgramRegistry,
+        workspace_files: impl Iterator<Item = (&'a Rc<PathBuf>, &'a Rc<WorkspaceFile>)>,
+    ) -> Vec<FallbackTree> {
+        let programs = collect_programs(shader_pack, registry, workspace_files);
+        let mut dimensions = programs.keys().map(|(dimension, _)| dimension.clone()).collect::<Vec<_>>();
+        dimensions.push(None);
+        dimensions.sort_unstable();
+        dimensions.dedup();
+
+        dimensions
+            .into_iter()
+            .map(|dimension| {
+                // Programs in dimension folders override programs in base folder
+                let find_files = |program: &str| {
+                    programs
+                        .get(&(dimension.clone(), program.to_owned()))
+                        .or_else(|| programs.get(&(None, program.to_owned())))
+                };
+                let mut fallbacks = registry
+                    .programs()
+                    .filter(|program| !program.indexed)
+                    .map(|program| {
+                        let used_program = registry.resolve(&program.name, |name| find_files(name).is_some());
+                        let files = used_program.and_then(find_files).map_or(vec![], |files| {
+                            files
+                                .iter()
+                                .map(|(file_path, _)| path_to_url(file_path))
+                                .collect()
+                        });
+                        ProgramFallback {
+                            program: program.name.clone(),
+                            stage: program_stage(&program.name).map(|(stage, _)| stage),
+                            loaders: program.loaders.clone(),
+                            used_program: used_program.map(|name| name.to_owned()),
+                            files,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                fallbacks.sort_unstable_by(|a, b| (a.stage, &a.program).cmp(&(b.stage, &b.program)));
+                FallbackTree {
+                    dimension,
+                    programs: fallbacks,
+                }
+            })
+            .collect()
+    }
+
+    /// Other programs falling back to this program
+    pub fn also_used_for(&self, program: &str) -> Vec<&ProgramFallback> {
+        self.programs
+            .iter()
+            .filter(|fallback| fallback.program != program && fallback.used_program.as_deref() == Some(program))
+            .collect()
+    }
+}
+
+impl ProgramRegistry {
+    /// Follow the fallback chain from a program until reaching an existing program.
+    pub fn resolve<'a>(&'a self, program: &'a str, exists: impl Fn(&str) -> bool) -> Option<&'a str> {
+        let mut visited = HashSet::new();
+        let mut current = program;
+        // Fallbacks from configuration may form a loop
+        while visited.insert(current) {
+            if exists(current) {
+                return Some(current);
+            }
+            current = self.program(current)?.fallback.as_deref()?;
+        }
+        None
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    path::{Path, PathBuf, MAIN_SEPARATOR},
+    rc::Rc,
+};
+
+use hashbrown::HashMap;
+use serde::Serialize;
+use tower_lsp::lsp_types::*;
+
+use crate::file::*;
+use crate::tree_parser::{FragmentOutput, TreeParser};
+
+mod fallback;
+mod program;
+mod render_target;
+
+pub use fallback::*;
+pub use program::*;
+pub use render_target::*;
+
+/// Stages of the render pipeline, in the order they run in a frame.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PipelineStage {
+    /// Compute programs run once when the pack is loaded
+    Setup,
+    Begin,
+    Shadow,
+    ShadowComp,
+    Prepare,
+    /// Opaque geometry, rendered before deferred programs
+    Gbuffers,
+    Deferred,
+    /// Translucent geometry, rendered after deferred programs
+    GbuffersTranslucent,
+    Composite,
+    Final,
+}
+
+impl PipelineStage {
+    /// Programs in gbuffers and shadow stages render geometries in the same time, so they have no order in the same stage.
+    pub fn is_geometry(&self) -> bool {
+        matches!(self, Self::Shadow | Self::Gbuffers | Self::GbuffersTranslucent)
+    }
+}
+
+/// Get the stage and the index in stage of a program name (file name without extension).
+pub fn program_stage(program: &str) -> Option<(PipelineStage, u32)> {
+    let (prefix, index) = match program.find(|char: char| char.is_ascii_digit()) {
+        Some(digit) => (
+            &program[..digit],
+            program[digit..].trim_end_matches(|char: char| !char.is_ascii_digit()),
+        ),
+        None => (program, ""),
+    };
+    let index = index.parse::<u32>().unwrap_or(0);
+    let stage = match prefix.split('_').next()? {
+        "setup" => PipelineStage::Setup,
+        "begin" => PipelineStage::Begin,
+        "shadow" => PipelineStage::Shadow,
+        "shadowcomp" => PipelineStage::ShadowComp,
+        "prepare" => PipelineStage::Prepare,
+        "deferred" => PipelineStage::Deferred,
+        "composite" => PipelineStage::Composite,
+        "final" => PipelineStage::Final,
+        "gbuffers" | "dh" => match program {
+            "gbuffers_water"
+            | "gbuffers_hand_water"
+            | "gbuffers_weather"
+            | "gbuffers_block_translucent"
+            | "gbuffers_entities_translucent"
+            | "gbuffers_particles_translucent"
+            | "dh_water" => PipelineStage::GbuffersTranslucent,
+            "dh_shadow" => PipelineStage::Shadow,
+            _ => PipelineStage::Gbuffers,
+        },
+        _ => return None,
+    };
+    Some((stage, index))
+}
+
+/// Split the path of a shader file into dimension folder and program name, suffixes of the program removed.
+pub fn program_of_file<'a>(registry: &ProgramRegistry, pack_path: &Path, file_path: &'a Path) -> Option<(Option<&'a str>, &'a str)> {
+    let relative_path = file_path.strip_prefix(pack_path).ok()?.to_str()?;
+    let (dimension, file_name) = match relative_path.split_once(MAIN_SEPARATOR) {
+        Some((dimension, file_name)) => (Some(dimension), file_name),
+        None => (None, relative_path),
+    };
+    Some((dimension, registry.program_name(file_name)?))
+}
+
+/// Canonical name of a buffer sampler, converting legacy names to `colortexN` and `shadowcolorN`
+pub fn canonical_buffer_name(name: &str) -> Option<String> {
+    let canonical = match name {
+        "gcolor" => "colortex0",
+        "gdepth" => "colortex1",
+        "gnormal" => "colortex2",
+        "composite" => "colortex3",
+        "gaux1" => "colortex4",
+        "gaux2" => "colortex5",
+        "gaux3" => "colortex6",
+        "gaux4" => "colortex7",
+        "shadowcolor" => "shadowcolor0",
+        _ => {
+            let index = name.strip_prefix("colortex").or_else(|| name.strip_prefix("shadowcolor"))?;
+            return index.parse::<u8>().ok().map(|_| name.to_owned());
+        }
+    };
+    Some(canonical.to_owned())
+}
+
+type ProgramFiles = Vec<(Rc<PathBuf>, Rc<WorkspaceFile>)>;
+
+/// Group shader files of a pack into programs, keyed by (dimension folder, program name)
+pub fn collect_programs<'a>(
+    shader_pack: &Rc<ShaderPack>, registry: &ProgramRegistry,
+    workspace_files: impl Iterator<Item = (&'a Rc<PathBuf>, &'a Rc<WorkspaceFile>)>,
+) -> HashMap<(Option<String>, String), ProgramFiles> {
+    let mut programs: HashMap<(Option<String>, String), ProgramFiles> = HashMap::new();
+    workspace_files
+        .filter(|(_, workspace_file)| {
+            let file_type = *workspace_file.file_type().borrow();
+            file_type != gl::NONE && file_type != gl::INVALID_ENUM && workspace_file.shader_pack() == shader_pack
+        })
+        .for_each(|(file_path, workspace_file)| {
+            if let Some((dimension, program)) = program_of_file(registry, &shader_pack.path, file_path) {
+                programs
+                    .entry((dimension.map(|dimension| dimension.to_owned()), program.to_owned()))
+                    .or_default()
+                    .push((file_path.clone(), workspace_file.clone()));
+            }
+        });
+    programs
+}
@@ -0,0 +1,249 @@
+use serde::Deserialize;
+
+use super::*;
+
+/// A shader stage of a program, decided by file extension.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ShaderStage {
+    Vertex,
+    Geometry,
+    Fragment,
+    Compute,
+}
+
+impl ShaderStage {
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "vsh" => Some(Self::Vertex),
+            "gsh" => Some(Self::Geometry),
+            "fsh" => Some(Self::Fragment),
+            "csh" => Some(Self::Compute),
+            _ => None,
+        }
+    }
+
+    pub fn gl_type(&self) -> u32 {
+        match self {
+            Self::Vertex => gl::VERTEX_SHADER,
+            Self::Geometry => gl::GEOMETRY_SHADER,
+            Self::Fragment => gl::FRAGMENT_SHADER,
+            Self::Compute => gl::COMPUTE_SHADER,
+        }
+    }
+}
+
+/// Shader loaders that read shader packs.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Loader {
+    OptiFine,
+    Iris,
+}
+
+/// A program that shader loaders look for in shader packs.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramInfo {
+    pub name: String,
+    pub stages: Vec<ShaderStage>,
+    /// Numbered programs from `name1` to `name99` are also allowed
+    #[serde(default)]
+    pub indexed: bool,
+    #[serde(default = "ProgramInfo::all_loaders")]
+    pub loaders: Vec<Loader>,
+    /// Program used instead when this program is missing
+    #[serde(default)]
+    pub fallback: Option<String>,
+    /// Suffixes allowed after the name, like `_a` for multiple compute shaders in a program
+    #[serde(default)]
+    pub suffixes: Vec<String>,
+    /// Stages of files named with suffixes
+    #[serde(default = "ProgramInfo::compute_stage")]
+    pub suffix_stages: Vec<ShaderStage>,
+}
+
+impl ProgramInfo {
+    fn all_loaders() -> Vec<Loader> {
+        vec![Loader::OptiFine, Loader::Iris]
+    }
+
+    fn compute_stage() -> Vec<ShaderStage> {
+        vec![ShaderStage::Compute]
+    }
+}
+
+const GEOMETRY: &[ShaderStage] = &[ShaderStage::Vertex, ShaderStage::Geometry, ShaderStage::Fragment];
+const COMPOSITE: &[ShaderStage] = &[
+    ShaderStage::Vertex,
+    ShaderStage::Geometry,
+    ShaderStage::Fragment,
+    ShaderStage::Compute,
+];
+const COMPUTE: &[ShaderStage] = &[ShaderStage::Compute];
+
+const BOTH: &[Loader] = &[Loader::OptiFine, Loader::Iris];
+const OPTIFINE: &[Loader] = &[Loader::OptiFine];
+const IRIS: &[Loader] = &[Loader::Iris];
+
+/// Iris allows multiple compute shaders in a program, with suffixes from `_a` to `_z`
+#[rustfmt::skip]
+const ALPHABET: &[&str] = &[
+    "_a", "_b", "_c", "_d", "_e", "_f", "_g", "_h", "_i", "_j", "_k", "_l", "_m",
+    "_n", "_o", "_p", "_q", "_r", "_s", "_t", "_u", "_v", "_w", "_x", "_y", "_z",
+];
+const NONE: &[&str] = &[];
+
+/// (name, stages, indexed, loaders, fallback, suffixes of compute shaders)
+type BuiltinProgram = (
+    &'static str,
+    &'static [ShaderStage],
+    bool,
+    &'static [Loader],
+    Option<&'static str>,
+    &'static [&'static str],
+);
+
+/// Programs known by shader loaders
+#[rustfmt::skip]
+static BUILTIN_PROGRAMS: &[BuiltinProgram] = &[
+    ("setup", COMPUTE, true, IRIS, None, ALPHABET),
+    ("begin", COMPOSITE, true, IRIS, None, ALPHABET),
+    ("shadow", GEOMETRY, false, BOTH, None, NONE),
+    ("shadow_solid", GEOMETRY, false, OPTIFINE, Some("shadow"), NONE),
+    ("shadow_cutout", GEOMETRY, false, OPTIFINE, Some("shadow"), NONE),
+    ("shadowcomp", COMPOSITE, true, BOTH, None, ALPHABET),
+    ("prepare", COMPOSITE, true, BOTH, None, ALPHABET),
+    ("gbuffers_basic", GEOMETRY, false, BOTH, None, NONE),
+    ("gbuffers_line", GEOMETRY, false, BOTH, Some("gbuffers_basic"), NONE),
+    ("gbuffers_textured", GEOMETRY, false, BOTH, Some("gbuffers_basic"), NONE),
+    ("gbuffers_textured_lit", GEOMETRY, false, BOTH, Some("gbuffers_textured"), NONE),
+    ("gbuffers_skybasic", GEOMETRY, false, BOTH, Some("gbuffers_basic"), NONE),
+    ("gbuffers_skytextured", GEOMETRY, false, BOTH, Some("gbuffers_textured"), NONE),
+    ("gbuffers_clouds", GEOMETRY, false, BOTH, Some("gbuffers_textured"), NONE),
+    ("gbuffers_terrain", GEOMETRY, false, BOTH, Some("gbuffers_textured_lit"), NONE),
+    ("gbuffers_terrain_solid", GEOMETRY, false, OPTIFINE, Some("gbuffers_terrain"), NONE),
+    ("gbuffers_terrain_cutout_mip", GEOMETRY, false, OPTIFINE, Some("gbuffers_terrain"), NONE),
+    ("gbuffers_terrain_cutout", GEOMETRY, false, OPTIFINE, Some("gbuffers_terrain"), NONE),
+    ("gbuffers_damagedblock", GEOMETRY, false, BOTH, Some("gbuffers_terrain"), NONE),
+    ("gbuffers_block", GEOMETRY, false, BOTH, Some("gbuffers_terrain"), NONE),
+    ("gbuffers_block_translucent", GEOMETRY, false, IRIS, Some("gbuffers_block"), NONE),
+    ("gbuffers_beaconbeam", GEOMETRY, false, BOTH, Some("gbuffers_textured"), NONE),
+    ("gbuffers_item", GEOMETRY, false, OPTIFINE, Some("gbuffers_textured_lit"), NONE),
+    ("gbuffers_entities", GEOMETRY, false, BOTH, Some("gbuffers_textured_lit"), NONE),
+    ("gbuffers_entities_translucent", GEOMETRY, false, IRIS, Some("gbuffers_entities"), NONE),
+    ("gbuffers_entities_glowing", GEOMETRY, false, BOTH, Some("gbuffers_entities"), NONE),
+    ("gbuffers_lightning", GEOMETRY, false, IRIS, Some("gbuffers_entities"), NONE),
+    ("gbuffers_armor_glint", GEOMETRY, false, BOTH, Some("gbuffers_textured"), NONE),
+    ("gbuffers_spidereyes", GEOMETRY, false, BOTH, Some("gbuffers_textured"), NONE),
+    ("gbuffers_particles", GEOMETRY, false, IRIS, Some("gbuffers_textured_lit"), NONE),
+    ("gbuffers_particles_translucent", GEOMETRY, false, IRIS, Some("gbuffers_particles"), NONE),
+    ("gbuffers_hand", GEOMETRY, false, BOTH, Some("gbuffers_textured_lit"), NONE),
+    ("gbuffers_weather", GEOMETRY, false, BOTH, Some("gbuffers_textured_lit"), NONE),
+    ("gbuffers_water", GEOMETRY, false, BOTH, Some("gbuffers_terrain"), NONE),
+    ("gbuffers_hand_water", GEOMETRY, false, BOTH, Some("gbuffers_hand"), NONE),
+    ("dh_terrain", GEOMETRY, false, IRIS, None, NONE),
+    ("dh_water", GEOMETRY, false, IRIS, Some("dh_terrain"), NONE),
+    ("dh_shadow", GEOMETRY, false, IRIS, None, NONE),
+    ("dh_generic", GEOMETRY, false, IRIS, None, NONE),
+    ("deferred", COMPOSITE, true, BOTH, None, ALPHABET),
+    ("composite", COMPOSITE, true, BOTH, None, ALPHABET),
+    ("final", COMPOSITE, false, BOTH, None, ALPHABET),
+];
+
+/// Programs recognized as shaders, built-in ones extended by configuration.
+#[derive(PartialEq)]
+pub struct ProgramRegistry {
+    programs: HashMap<String, ProgramInfo>,
+}
+
+impl ProgramRegistry {
+    /// Built-in programs with extra programs from configuration. Extra programs override built-in ones with the same name.
+    pub fn new(extra_programs: &[ProgramInfo]) -> Self {
+        let mut programs = BUILTIN_PROGRAMS
+            .iter()
+            .map(|(name, stages, indexed, loaders, fallback, suffixes)| {
+                let program = ProgramInfo {
+                    name: name.to_string(),
+                    stages: stages.to_vec(),
+                    indexed: *indexed,
+                    loaders: loaders.to_vec(),
+                    fallback: fallback.map(|fallback| fallback.to_owned()),
+                    suffixes: suffixes.iter().map(|suffix| suffix.to_string()).collect(),
+                    suffix_stages: ProgramInfo::compute_stage(),
+                };
+                (program.name.clone(), program)
+            })
+            .collect::<HashMap<_, _>>();
+        programs.extend(extra_programs.iter().map(|program| (program.name.clone(), program.clone())));
+        ProgramRegistry { programs }
+    }
+
+    pub fn program(&self, name: &str) -> Option<&ProgramInfo> {
+        self.programs.get(name)
+    }
+
+    pub fn programs(&self) -> impl Iterator<Item = &ProgramInfo> {
+        self.programs.values()
+    }
+
+    /// Match a file name like `composite2_a.csh` to its program and stage.
+    pub fn match_file(&self, file_name: &str) -> Option<(&ProgramInfo, ShaderStage)> {
+        let (name, extension) = file_name.rsplit_once('.')?;
+        let stage = ShaderStage::from_extension(extension)?;
+        let (_, program) = self.match_file_name(name, stage)?;
+        program.stages.contains(&stage).then_some((program, stage))
+    }
+
+    /// Program name of a file name without its suffix, like `composite2` of `composite2_a.csh`.
+    pub fn program_name<'a>(&self, file_name: &'a str) -> Option<&'a str> {
+        let (name, extension) = file_name.rsplit_once('.')?;
+        let stage = ShaderStage::from_extension(extension)?;
+        self.match_file_name(name, stage).map(|(name, _)| name)
+    }
+
+    /// Match a file name without extension to its program, and the program name without suffix.
+    fn match_file_name<'a>(&self, name: &'a str, stage: ShaderStage) -> Option<(&'a str, &ProgramInfo)> {
+        match self.match_name(name) {
+            Some(program) => Some((name, program)),
+            None => self.match_suffixed_name(name, stage),
+        }
+    }
+
+    /// Match a path relative to shader pack root, in the root or a dimension folder.
+    pub fn match_path(&self, shader_pack: &ShaderPack, relative_path: &str) -> Option<(&ProgramInfo, ShaderStage)> {
+        match relative_path.split_once(MAIN_SEPARATOR) {
+            Some((folder, file_name)) if shader_pack.is_dimension_folder(folder) => self.match_file(file_name),
+            Some(_) => None,
+            None => self.match_file(relative_path),
+        }
+    }
+
+    /// Match a program name like `composite2` to its program.
+    fn match_name(&self, name: &str) -> Option<&ProgramInfo> {
+        if let Some(program) = self.programs.get(name) {
+            return Some(program);
+        }
+        let base_name = name.trim_end_matches(|char: char| char.is_ascii_digit());
+        let index = &name[base_name.len()..];
+        if index.is_empty() || index.len() > 2 || index.starts_with('0') {
+            return None;
+        }
+        self.programs.get(base_name).filter(|program| program.indexed)
+    }
+
+    /// Match a program name with a suffix like `composite2_a` to the program allowing this suffix in this stage.
+    fn match_suffixed_name<'a>(&self, name: &'a str, stage: ShaderStage) -> Option<(&'a str, &ProgramInfo)> {
+        self.programs
+            .values()
+            .filter(|program| program.suffix_stages.contains(&stage))
+            .find_map(|program| {
+                program.suffixes.iter().find_map(|suffix| {
+                    let name = name.strip_suffix(suffix.as_str())?;
+                    self.match_name(name)
+                        .filter(|matched| matched.name == program.name)
+                        .map(|_| (name, program))
+                })
+            })
+    }
+}
@@ -0,0 +1,326 @@
+use hashbrown::HashSet;
+
+use super::*;
+
+/// A program in the render pipeline, and buffers it reads and writes.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderPass {
+    pub program: String,
+    pub stage: PipelineStage,
+    #[serde(skip)]
+    index: u32,
+    /// Shader files of this program
+    pub files: Vec<Url>,
+    /// Buffers written by fragment outputs, in the order of render target directive
+    pub writes: Vec<String>,
+    pub reads: Vec<String>,
+    /// Location of the render target directive
+    #[serde(skip)]
+    directive: Option<(Rc<PathBuf>, Range)>,
+    /// Location of first usage of each read buffer
+    #[serde(skip)]
+    read_locations: HashMap<String, (Rc<PathBuf>, Range)>,
+    /// Fragment outputs mismatching the render target directive
+    #[serde(skip)]
+    output_issues: Vec<(Rc<PathBuf>, Range, String)>,
+}
+
+/// A buffer read without earlier writes, or written without later reads.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BufferIssue {
+    pub program: String,
+    pub buffer: String,
+}
+
+/// Render pipeline of the base folder or a dimension folder.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderPipeline {
+    /// Dimension folder name. `None` for programs in the base folder
+    pub dimension: Option<String>,
+    /// Passes in execution order
+    pub passes: Vec<RenderPass>,
+    /// Buffers declared to be not cleared every frame, they may be read before written in a frame.
+    pub persistent_buffers: Vec<String>,
+    pub unwritten_reads: Vec<BufferIssue>,
+    pub unread_writes: Vec<BufferIssue>,
+}
+
+impl RenderPass {
+    fn new(program: &str, stage: PipelineStage, index: u32, files: &ProgramFiles, persistent_buffers: &mut HashSet<String>) -> Self {
+        let mut render_pass = RenderPass {
+            program: program.to_owned(),
+            stage,
+            index,
+            files: vec![],
+            writes: vec![],
+            reads: vec![],
+            directive: None,
+            read_locations: HashMap::new(),
+            output_issues: vec![],
+        };
+        let mut directive = None;
+        let mut outputs = vec![];
+        let mut has_fragment = false;
+        for (file_path, workspace_file) in files {
+            render_pass.files.push(path_to_url(file_path));
+            let is_fragment = *workspace_file.file_type().borrow() == gl::FRAGMENT_SHADER;
+            has_fragment |= is_fragment;
+            for (include_path, include_file) in WorkspaceFile::include_tree(workspace_file, file_path) {
+                let tree = include_file.tree().borrow();
+                let content = include_file.content().borrow();
+                let line_mapping = include_file.line_mapping().borrow();
+
+                for (buffer, range) in TreeParser::render_target_reads(&tree, &content, &line_mapping) {
+                    render_pass
+                        .read_locations
+                        .entry(buffer)
+                        .or_insert_with(|| (include_path.clone(), range));
+                }
+                persistent_buffers.extend(TreeParser::persistent_buffers(&tree, &content));
+                if is_fragment {
+                    outputs.extend(
+                        TreeParser::fragment_outputs(&tree, &content, &line_mapping)
+                            .into_iter()
+                            .map(|output| (include_path.clone(), output)),
+                    );
+                    if let Some(file_directive) = TreeParser::render_target_directive(&tree, &content, &line_mapping) {
+                        directive = Some((include_path.clone(), file_directive));
+                    }
+                }
+            }
+        }
+
+        let buffer_prefix = match stage {
+            PipelineStage::Shadow | PipelineStage::ShadowComp => "shadowcolor",
+            _ => "colortex",
+        };
+        match directive {
+            Some((file_path, directive)) => {
+                render_pass.writes = directive
+                    .targets
+                    .iter()
+                    .map(|target| buffer_prefix.to_owned() + &target.to_string())
+                    .collect();
+                render_pass.directive = Some((file_path, directive.range));
+                render_pass.check_outputs(&outputs, &directive.targets, buffer_prefix);
+            }
+            // Without directive, only the first buffer will be written
+            None if has_fragment && buffer_prefix == "colortex" => render_pass.writes.push("colortex0".to_owned()),
+            None => {}
+        }
+        render_pass.reads = render_pass.read_locations.keys().cloned().collect();
+        render_pass.reads.sort_unstable();
+        render_pass
+    }
+
+    /// Check fragment outputs against targets in render target directive, mismatched outputs and targets will not be written.
+    fn check_outputs(&mut self, outputs: &[(Rc<PathBuf>, FragmentOutput)], targets: &[u8], buffer_prefix: &str) {
+        let target_count = targets.len() as u32;
+        // A single output without location is bound to location 0
+        let declared_count = outputs.iter().filter(|(_, output)| output.declared).count();
+        let mut written = vec![false; targets.len()];
+        let mut unknown_location = false;
+        for (file_path, output) in outputs {
+            let location = match output.location {
+                Some(location) => location,
+                None if output.declared && declared_count == 1 => 0,
+                None => {
+                    unknown_location = true;
+                    if output.declared {
+                        let message = format!(
+                            "Fragment output `{}` has no `layout(location)`, its render target is undefined with multiple outputs",
+                            output.name
+                        );
+                        self.output_issues.push((file_path.clone(), output.range, message));
+                    }
+                    continue;
+                }
+            };
+            for location in location..location + output.size {
+                match written.get_mut(location as usize) {
+                    Some(written) => *written = true,
+                    None => {
+                        let message = format!(
+                            "Fragment output `{}` at location {} has no render target, the directive only declares {} target(s)",
+                            output.name, location, target_count
+                        );
+                        self.output_issues.push((file_path.clone(), output.range, message));
+                        break;
+                    }
+                }
+            }
+        }
+
+        if unknown_location {
+            return;
+        }
+        if let Some((file_path, range)) = &self.directive {
+            for (location, target) in targets.iter().enumerate().filter(|(location, _)| !written[*location]) {
+                let message = format!(
+                    "Render target `{}{}` at location {} is not written by any fragment output",
+                    buffer_prefix, target, location
+                );
+                self.output_issues.push((file_path.clone(), *range, message));
+            }
+        }
+    }
+
+    /// Whether this pass runs before another pass. Passes rendering geometries in the same stage have no order.
+    fn runs_before(&self, other: &RenderPass) -> bool {
+        match self.stage.cmp(&other.stage) {
+            std::cmp::Ordering::Less => true,
+            std::cmp::Ordering::Equal => self.stage.is_geometry() || self.index < other.index,
+            std::cmp::Ordering::Greater => false,
+        }
+    }
+}
+
+impl RenderPipeline {
+    /// Build render pipelines of the base folder and each dimension folder in this pack.
+    pub fn build<'a>(
+        shader_pack: &Rc<ShaderPack>, registry: &ProgramRegistry,
+        workspace_files: impl Iterator<Item = (&'a Rc<PathBuf>, &'a Rc<WorkspaceFile>)>,
+    ) -> Vec<RenderPipeline> {
+        let programs = collect_programs(shader_pack, registry, workspace_files);
+        let mut dimensions = programs.keys().map(|(dimension, _)| dimension.clone()).collect::<Vec<_>>();
+        dimensions.sort_unstable();
+        dimensions.dedup();
+
+        dimensions
+            .into_iter()
+            .map(|dimension| {
+                // Dimension folders override programs with the same name in base folder
+                let mut pipeline_programs = programs
+                    .iter()
+                    .filter(|((program_dimension, _), _)| program_dimension.is_none())
+                    .map(|((_, program), files)| (program, files))
+                    .collect::<HashMap<_, _>>();
+                if dimension.is_some() {
+                    pipeline_programs.extend(
+                        programs
+                            .iter()
+                            .filter(|((program_dimension, _), _)| *program_dimension == dimension)
+                            .map(|((_, program), files)| (program, files)),
+                    );
+                }
+
+                let mut persistent_buffers = HashSet::new();
+                let mut passes = pipeline_programs
+                    .into_iter()
+                    .filter_map(|(program, files)| {
+                        let (stage, index) = program_stage(program)?;
+                        Some(RenderPass::new(program, stage, index, files, &mut persistent_buffers))
+                    })
+                    .collect::<Vec<_>>();
+                passes.sort_unstable_by(|a, b| (a.stage, a.index, &a.program).cmp(&(b.stage, b.index, &b.program)));
+
+                let mut persistent_buffers = persistent_buffers.into_iter().collect::<Vec<_>>();
+                persistent_buffers.sort_unstable();
+
+                let mut pipeline = RenderPipeline {
+                    dimension,
+                    passes,
+                    persistent_buffers,
+                    unwritten_reads: vec![],
+                    unread_writes: vec![],
+                };
+                pipeline.check();
+                pipeline
+            })
+            .collect()
+    }
+
+    fn check(&mut self) {
+        let has_final = self.passes.iter().any(|pass| pass.stage == PipelineStage::Final);
+        for pass in &self.passes {
+            for buffer in &pass.reads {
+                let written = self.persistent_buffers.contains(buffer)
+                    || self
+                        .passes
+                        .iter()
+                        .any(|other| !std::ptr::eq(pass, other) && other.runs_before(pass) && other.writes.contains(buffer));
+                if !written {
+                    self.unwritten_reads.push(BufferIssue {
+                        program: pass.program.clone(),
+                        buffer: buffer.clone(),
+                    });
+                }
+            }
+            for buffer in &pass.writes {
+                // Without final program, colortex0 will be drawn to screen
+                let read = self.persistent_buffers.contains(buffer)
+                    || (!has_final && buffer == "colortex0")
+                    || self
+                        .passes
+                        .iter()
+                        .any(|other| !std::ptr::eq(pass, other) && pass.runs_before(other) && other.reads.contains(buffer));
+                if !read {
+                    self.unread_writes.push(BufferIssue {
+                        program: pass.program.clone(),
+                        buffer: buffer.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn pass(&self, program: &str) -> &RenderPass {
+        self.passes.iter().find(|pass| pass.program == program).unwrap()
+    }
+
+    /// Convert issues of these pipelines into diagnostics of related files.
+    pub fn diagnostics(pipelines: &[RenderPipeline]) -> HashMap<PathBuf, Vec<Diagnostic>> {
+        let mut diagnostics: HashMap<PathBuf, Vec<Diagnostic>> = HashMap::new();
+        let mut push_diagnostic = |file_path: &Rc<PathBuf>, range: Range, severity: DiagnosticSeverity, message: String| {
+            let file_diagnostics = diagnostics.entry(file_path.to_path_buf()).or_default();
+            // Include files and base programs can be shared by multiple pipelines
+            if !file_diagnostics
+                .iter()
+                .any(|diagnostic| diagnostic.range == range && diagnostic.message == message)
+            {
+                file_diagnostics.push(Diagnostic {
+                    range,
+                    severity: Some(severity),
+                    source: Some("mcshader-pipeline".to_owned()),
+                    message,
+                    ..Default::default()
+                });
+            }
+        };
+
+        for pipeline in pipelines {
+            let dimension = pipeline
+                .dimension
+                .as_ref()
+                .map_or(String::new(), |dimension| " in ".to_owned() + dimension);
+            for pass in &pipeline.passes {
+                for (file_path, range, message) in &pass.output_issues {
+                    push_diagnostic(file_path, *range, DiagnosticSeverity::WARNING, message.clone());
+                }
+            }
+            for issue in &pipeline.unwritten_reads {
+                let pass = pipeline.pass(&issue.program);
+                let (file_path, range) = pass.read_locations.get(&issue.buffer).unwrap();
+                let message = format!(
+                    "`{}` is read by `{}`{} but no earlier pass writes it",
+                    issue.buffer, issue.program, dimension
+                );
+                push_diagnostic(file_path, *range, DiagnosticSeverity::WARNING, message);
+            }
+            for issue in &pipeline.unread_writes {
+                let pass = pipeline.pass(&issue.program);
+                if let Some((file_path, range)) = &pass.directive {
+                    let message = format!(
+                        "`{}` written by `{}`{} is never read by later passes",
+                        issue.buffer, issue.program, dimension
+                    );
+                    push_diagnostic(file_path, *range, DiagnosticSeverity::INFORMATION, message);
+                }
+            }
+        }
+        diagnostics
+    }
+}
@@ -0,0 +1,89 @@
+use super::*;
+
+fn error_diagnostic(range: Range, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("mcshader-properties".to_owned()),
+        message,
+        ..Default::default()
+    }
+}
+
+impl ShaderProperties {
+    pub fn new(content: &str) -> Self {
+        let mut properties = ShaderProperties::default();
+        let entries = parse_properties(content);
+
+        // Custom uniforms and variables can reference each other, so collect all of them before checking expressions.
+        let declarations = entries
+            .iter()
+            .filter_map(|entry| {
+                let (is_uniform, rest) = if let Some(rest) = entry.key.strip_prefix("uniform.") {
+                    (true, rest)
+                } else {
+                    (false, entry.key.strip_prefix("variable.")?)
+                };
+                Some((entry, is_uniform, rest.split_once('.')))
+            })
+            .collect::<Vec<_>>();
+
+        for (entry, is_uniform, type_name) in &declarations {
+            let (type_name, name) = match type_name {
+                Some((type_name, name)) if !name.is_empty() => (*type_name, *name),
+                _ => {
+                    properties.diagnostics.push(error_diagnostic(
+                        entry.key_range,
+                        "Custom uniform should be declared as `uniform.<type>.<name>` or `variable.<type>.<name>`".to_owned(),
+                    ));
+                    continue;
+                }
+            };
+            match UniformType::from_name(type_name) {
+                Some(uniform_type) => {
+                    properties.custom_uniforms.insert(
+                        name.to_owned(),
+                        CustomUniform {
+                            uniform_type,
+                            is_uniform: *is_uniform,
+                            range: entry.key_range,
+                        },
+                    );
+                }
+                None => properties.diagnostics.push(error_diagnostic(
+                    entry.key_range,
+                    format!(
+                        "Unknown custom uniform type `{}`, expected one of bool, int, float, vec2, vec3, vec4",
+                        type_name
+                    ),
+                )),
+            }
+        }
+
+        for (entry, _, type_name) in &declarations {
+            let name = match type_name {
+                Some((_, name)) => *name,
+                None => continue,
+            };
+            let uniform_type = match properties.custom_uniforms.get(name) {
+                Some(custom_uniform) if custom_uniform.range == entry.key_range => custom_uniform.uniform_type,
+                _ => continue,
+            };
+            let mut checker = ExpressionChecker::new(entry, &properties.custom_uniforms);
+            match checker.check() {
+                Some(value_type) if !uniform_type.accepts(value_type) => properties.diagnostics.push(error_diagnostic(
+                    entry.range(0, entry.value.len()),
+                    format!(
+                        "Expression type `{}` does not match declared type `{}`",
+                        value_type.name(),
+                        uniform_type.name()
+                    ),
+                )),
+                _ => {}
+            }
+            properties.diagnostics.extend(checker.diagnostics);
+        }
+
+        properties
+    }
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+/// Dimension folders declared in `dimension.properties`, supported by Iris.
+#[derive(Default)]
+pub struct DimensionProperties {
+    /// Folder names and dimension ids using them, like `the_end` to `minecraft:the_end`
+    pub folders: HashMap<String, Vec<String>>,
+}
+
+impl DimensionProperties {
+    pub fn new(content: &str) -> Self {
+        let folders = parse_properties(content)
+            .into_iter()
+            .filter_map(|entry| {
+                let folder = entry.key.strip_prefix("dimension.")?;
+                let dimensions = entry.value.split_whitespace().map(|dimension| dimension.to_owned()).collect();
+                (!folder.is_empty()).then(|| (folder.to_owned(), dimensions))
+            })
+            .collect();
+        DimensionProperties { folders }
+    }
+}
@@ -0,0 +1,531 @@
+use lazy_static::lazy_static;
+
+use super::*;
+
+lazy_static! {
+    /// Built-in parameters that Optifine and Iris provide to custom uniform expressions
+    static ref BUILTIN_PARAMETERS: HashMap<&'static str, UniformType> = {
+        use UniformType::*;
+        HashMap::from([
+            ("pi", Float),
+            ("true", Bool),
+            ("false", Bool),
+            ("heldItemId", Int),
+            ("heldBlockLightValue", Int),
+            ("heldItemId2", Int),
+            ("heldBlockLightValue2", Int),
+            ("fogMode", Int),
+            ("fogStart", Float),
+            ("fogEnd", Float),
+            ("fogDensity", Float),
+            ("fogColor", Vec3),
+            ("skyColor", Vec3),
+            ("worldTime", Int),
+            ("worldDay", Int),
+            ("moonPhase", Int),
+            ("frameCounter", Int),
+            ("frameTime", Float),
+            ("frameTimeCounter", Float),
+            ("sunAngle", Float),
+            ("shadowAngle", Float),
+            ("rainStrength", Float),
+            ("thunderStrength", Float),
+            ("sunPathRotation", Float),
+            ("cloudTime", Float),
+            ("aspectRatio", Float),
+            ("viewWidth", Float),
+            ("viewHeight", Float),
+            ("near", Float),
+            ("far", Float),
+            ("sunPosition", Vec3),
+            ("moonPosition", Vec3),
+            ("shadowLightPosition", Vec3),
+            ("upPosition", Vec3),
+            ("cameraPosition", Vec3),
+            ("previousCameraPosition", Vec3),
+            ("cameraPositionFract", Vec3),
+            ("previousCameraPositionFract", Vec3),
+            ("eyePosition", Vec3),
+            ("relativeEyePosition", Vec3),
+            ("playerLookVector", Vec3),
+            ("playerBodyVector", Vec3),
+            ("lightningBoltPosition", Vec4),
+            ("wetness", Float),
+            ("eyeAltitude", Float),
+            ("eyeBrightness", Vec2),
+            ("eyeBrightnessSmooth", Vec2),
+            ("terrainTextureSize", Vec2),
+            ("terrainIconSize", Int),
+            ("isEyeInWater", Int),
+            ("nightVision", Float),
+            ("blindness", Float),
+            ("darknessFactor", Float),
+            ("darknessLightFactor", Float),
+            ("screenBrightness", Float),
+            ("hideGUI", Int),
+            ("centerDepthSmooth", Float),
+            ("atlasSize", Vec2),
+            ("playerMood", Float),
+            ("renderStage", Int),
+            ("bossBattle", Int),
+            ("currentPlayerHealth", Float),
+            ("maxPlayerHealth", Float),
+            ("currentPlayerHunger", Float),
+            ("maxPlayerHunger", Float),
+            ("currentPlayerAir", Float),
+            ("maxPlayerAir", Float),
+            ("is_first_person", Bool),
+            ("is_spectator", Bool),
+            ("biome", Int),
+            ("biome_category", Int),
+            ("biome_precipitation", Int),
+            ("temperature", Float),
+            ("rainfall", Float),
+            ("is_alive", Bool),
+            ("is_burning", Bool),
+            ("is_child", Bool),
+            ("is_glowing", Bool),
+            ("is_hurt", Bool),
+            ("is_in_hand", Bool),
+            ("is_in_item_frame", Bool),
+            ("is_in_ground", Bool),
+            ("is_in_gui", Bool),
+            ("is_in_lava", Bool),
+            ("is_in_water", Bool),
+            ("is_invisible", Bool),
+            ("is_on_ground", Bool),
+            ("is_on_head", Bool),
+            ("is_on_shoulder", Bool),
+            ("is_ridden", Bool),
+            ("is_riding", Bool),
+            ("is_sitting", Bool),
+            ("is_sneaking", Bool),
+            ("is_sprinting", Bool),
+            ("is_tamed", Bool),
+            ("is_wet", Bool),
+        ])
+    };
+}
+
+/// Prefixes of biome, category and precipitation constants
+const CONSTANT_PREFIXES: [&str; 3] = ["BIOME_", "CAT_", "PPT_"];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Number,
+    Identifier,
+    Operator,
+    LeftParen,
+    RightParen,
+    Comma,
+    Dot,
+}
+
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+/// Type checker for Optifine custom uniform expressions.
+///
+/// Syntax errors abort checking and return `None`, while type errors are reported and checking continues.
+/// `None` is also used as the type of values that can not be inferred, which will never report further errors.
+pub struct ExpressionChecker<'a> {
+    entry: &'a PropertyEntry<'a>,
+    custom_uniforms: &'a HashMap<String, CustomUniform>,
+    tokens: Vec<Token>,
+    index: usize,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> ExpressionChecker<'a> {
+    pub fn new(entry: &'a PropertyEntry<'a>, custom_uniforms: &'a HashMap<String, CustomUniform>) -> Self {
+        ExpressionChecker {
+            entry,
+            custom_uniforms,
+            tokens: vec![],
+            index: 0,
+            diagnostics: vec![],
+        }
+    }
+
+    fn report(&mut self, start: usize, end: usize, severity: DiagnosticSeverity, message: String) {
+        self.diagnostics.push(Diagnostic {
+            range: self.entry.range(start, end),
+            severity: Some(severity),
+            source: Some("mcshader-properties".to_owned()),
+            message,
+            ..Default::default()
+        });
+    }
+
+    fn tokenize(&mut self) -> bool {
+        let value = self.entry.value.as_bytes();
+        let mut index = 0;
+        while index < value.len() {
+            let start = index;
+            let kind = match value[index] {
+                b' ' | b'\t' | b'\r' => {
+                    index += 1;
+                    continue;
+                }
+                b'0'..=b'9' => {
+                    while index < value.len() && (value[index].is_ascii_digit() || value[index] == b'.') {
+                        index += 1;
+                    }
+                    TokenKind::Number
+                }
+                b'a'..=b'z' | b'A'..=b'Z' | b'_' => {
+                    while index < value.len() && (value[index].is_ascii_alphanumeric() || value[index] == b'_') {
+                        index += 1;
+                    }
+                    TokenKind::Identifier
+                }
+                b'(' => {
+                    index += 1;
+                    TokenKind::LeftParen
+                }
+                b')' => {
+                    index += 1;
+                    TokenKind::RightParen
+                }
+                b',' => {
+                    index += 1;
+                    TokenKind::Comma
+                }
+                b'.' => {
+                    index += 1;
+                    TokenKind::Dot
+                }
+                b'&' | b'|' | b'=' if value.get(index + 1) == Some(&value[index]) => {
+                    index += 2;
+                    TokenKind::Operator
+                }
+                b'!' | b'<' | b'>' => {
+                    index += if value.get(index + 1) == Some(&b'=') { 2 } else { 1 };
+                    TokenKind::Operator
+                }
+                b'+' | b'-' | b'*' | b'/' | b'%' => {
+                    index += 1;
+                    TokenKind::Operator
+                }
+                _ => {
+                    let end = start + self.entry.value[start..].chars().next().map_or(1, |char| char.len_utf8());
+                    self.report(
+                        start,
+                        end,
+                        DiagnosticSeverity::ERROR,
+                        "Unexpected character in expression".to_owned(),
+                    );
+                    return false;
+                }
+            };
+            self.tokens.push(Token { kind, start, end: index });
+        }
+        true
+    }
+
+    fn text(&self, token: &Token) -> &'a str {
+        &self.entry.value[token.start..token.end]
+    }
+
+    fn peek_operator(&self, operators: &[&str]) -> Option<&'a str> {
+        let token = self.tokens.get(self.index)?;
+        let text = self.text(token);
+        (token.kind == TokenKind::Operator && operators.contains(&text)).then_some(text)
+    }
+
+    fn expect(&mut self, kind: TokenKind, message: &str) -> Option<()> {
+        match self.tokens.get(self.index) {
+            Some(token) if token.kind == kind => {
+                self.index += 1;
+                Some(())
+            }
+            Some(token) => {
+                let (start, end) = (token.start, token.end);
+                self.report(start, end, DiagnosticSeverity::ERROR, message.to_owned());
+                None
+            }
+            None => {
+                let end = self.entry.value.trim_end().len();
+                self.report(end, end, DiagnosticSeverity::ERROR, message.to_owned());
+                None
+            }
+        }
+    }
+
+    /// Check the whole expression and returns its type
+    pub fn check(&mut self) -> Option<UniformType> {
+        if !self.tokenize() {
+            return None;
+        }
+        if self.tokens.is_empty() {
+            let end = self.entry.value.len();
+            self.report(0, end, DiagnosticSeverity::ERROR, "Empty expression".to_owned());
+            return None;
+        }
+        let result = self.binary(0).ok()?;
+        if let Some(token) = self.tokens.get(self.index) {
+            let (start, end) = (token.start, token.end);
+            self.report(start, end, DiagnosticSeverity::ERROR, "Unexpected token in expression".to_owned());
+            return None;
+        }
+        result
+    }
+
+    /// Parse binary operators by precedence level. `Err` marks a syntax error.
+    fn binary(&mut self, level: usize) -> Result<Option<UniformType>, ()> {
+        const LEVELS: [&[&str]; 5] = [&["||"], &["&&"], &["==", "!=", "<", "<=", ">", ">="], &["+", "-"], &["*", "/", "%"]];
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+        let start = self.tokens.get(self.index).map_or(0, |token| token.start);
+        let mut left = self.binary(level + 1)?;
+        while let Some(operator) = self.peek_operator(LEVELS[level]) {
+            self.index += 1;
+            let right = self.binary(level + 1)?;
+            let end = self.tokens.get(self.index - 1).map_or(start, |token| token.end);
+            left = match level {
+                0 | 1 => {
+                    self.expect_type(left, right, &[UniformType::Bool], start, end, operator);
+                    Some(UniformType::Bool)
+                }
+                2 => {
+                    if operator == "==" || operator == "!=" {
+                        self.expect_type(
+                            left,
+                            right,
+                            &[UniformType::Bool, UniformType::Int, UniformType::Float],
+                            start,
+                            end,
+                            operator,
+                        );
+                    } else {
+                        self.expect_type(left, right, &[UniformType::Int, UniformType::Float], start, end, operator);
+                    }
+                    Some(UniformType::Bool)
+                }
+                _ => self
+                    .expect_type(left, right, &[UniformType::Int, UniformType::Float], start, end, operator)
+                    .then_some(UniformType::Float),
+            };
+        }
+        Ok(left)
+    }
+
+    /// Report an error if operands are not allowed for this operator. Returns false if error reported.
+    fn expect_type(
+        &mut self, left: Option<UniformType>, right: Option<UniformType>, allowed: &[UniformType], start: usize, end: usize, operator: &str,
+    ) -> bool {
+        let valid = |value: Option<UniformType>| value.is_none_or(|value| allowed.contains(&value));
+        let same_kind = match (left, right) {
+            (Some(UniformType::Bool), Some(right)) => right == UniformType::Bool,
+            (Some(left), Some(UniformType::Bool)) => left == UniformType::Bool,
+            _ => true,
+        };
+        if !valid(left) || !valid(right) || !same_kind {
+            let message = format!(
+                "Operator `{}` can not be applied to `{}` and `{}`",
+                operator,
+                left.map_or("unknown", |value| value.name()),
+                right.map_or("unknown", |value| value.name())
+            );
+            self.report(start, end, DiagnosticSeverity::ERROR, message);
+            return false;
+        }
+        true
+    }
+
+    fn unary(&mut self) -> Result<Option<UniformType>, ()> {
+        if let Some(operator) = self.peek_operator(&["-", "+", "!"]) {
+            let token = &self.tokens[self.index];
+            let start = token.start;
+            self.index += 1;
+            let value = self.unary()?;
+            let end = self.tokens[self.index - 1].end;
+            let allowed = if operator == "!" { UniformType::Bool } else { UniformType::Float };
+            if value.is_some_and(|value| !allowed.accepts(value)) {
+                let message = format!("Operator `{}` can not be applied to `{}`", operator, value.unwrap().name());
+                self.report(start, end, DiagnosticSeverity::ERROR, message);
+            }
+            return Ok(if operator == "!" { Some(UniformType::Bool) } else { value });
+        }
+        let mut value = self.primary()?;
+        while self.tokens.get(self.index).is_some_and(|token| token.kind == TokenKind::Dot) {
+            self.index += 1;
+            let component = match self.tokens.get(self.index) {
+                Some(token) if token.kind == TokenKind::Identifier => token,
+                _ => {
+                    self.expect(TokenKind::Identifier, "Expected vector component after `.`");
+                    return Err(());
+                }
+            };
+            let (start, end) = (component.start, component.end);
+            let component_name = self.text(component);
+            self.index += 1;
+            let size = match value {
+                Some(UniformType::Vec2) => 2,
+                Some(UniformType::Vec3) => 3,
+                Some(UniformType::Vec4) => 4,
+                None => {
+                    value = None;
+                    continue;
+                }
+                Some(other) => {
+                    let message = format!("Type `{}` has no component `{}`", other.name(), component_name);
+                    self.report(start, end, DiagnosticSeverity::ERROR, message);
+                    value = None;
+                    continue;
+                }
+            };
+            let index = match component_name {
+                "x" | "r" => 0,
+                "y" | "g" => 1,
+                "z" | "b" => 2,
+                "w" | "a" => 3,
+                _ => 4,
+            };
+            if index >= size {
+                let message = format!("Type `{}` has no component `{}`", value.unwrap().name(), component_name);
+                self.report(start, end, DiagnosticSeverity::ERROR, message);
+            }
+            value = Some(UniformType::Float);
+        }
+        Ok(value)
+    }
+
+    fn primary(&mut self) -> Result<Option<UniformType>, ()> {
+        let token = match self.tokens.get(self.index) {
+            Some(token) => token,
+            None => {
+                self.expect(TokenKind::Identifier, "Unexpected end of expression");
+                return Err(());
+            }
+        };
+        let (start, end) = (token.start, token.end);
+        match token.kind {
+            TokenKind::Number => {
+                self.index += 1;
+                Ok(Some(UniformType::Float))
+            }
+            TokenKind::LeftParen => {
+                self.index += 1;
+                let value = self.binary(0)?;
+                self.expect(TokenKind::RightParen, "Expected `)`").ok_or(())?;
+                Ok(value)
+            }
+            TokenKind::Identifier => {
+                let name = self.text(token);
+                self.index += 1;
+                if self.tokens.get(self.index).is_some_and(|token| token.kind == TokenKind::LeftParen) {
+                    self.index += 1;
+                    let mut arguments = vec![];
+                    if self.tokens.get(self.index).is_some_and(|token| token.kind == TokenKind::RightParen) {
+                        self.index += 1;
+                    } else {
+                        loop {
+                            arguments.push(self.binary(0)?);
+                            match self.tokens.get(self.index) {
+                                Some(token) if token.kind == TokenKind::Comma => self.index += 1,
+                                _ => {
+                                    self.expect(TokenKind::RightParen, "Expected `,` or `)`").ok_or(())?;
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                    let end = self.tokens[self.index - 1].end;
+                    Ok(self.function(name, &arguments, start, end))
+                } else if let Some(custom_uniform) = self.custom_uniforms.get(name) {
+                    Ok(Some(custom_uniform.uniform_type))
+                } else if let Some(parameter) = BUILTIN_PARAMETERS.get(name) {
+                    Ok(Some(*parameter))
+                } else if CONSTANT_PREFIXES.iter().any(|prefix| name.starts_with(prefix)) {
+                    Ok(Some(UniformType::Int))
+                } else {
+                    self.report(start, end, DiagnosticSeverity::WARNING, format!("Unknown parameter `{}`", name));
+                    Ok(None)
+                }
+            }
+            _ => {
+                self.report(start, end, DiagnosticSeverity::ERROR, "Expected value in expression".to_owned());
+                Err(())
+            }
+        }
+    }
+
+    fn function(&mut self, name: &str, arguments: &[Option<UniformType>], start: usize, end: usize) -> Option<UniformType> {
+        let float = Some(UniformType::Float);
+        let is_float = |value: &Option<UniformType>| value.is_none_or(|value| UniformType::Float.accepts(value));
+        // (minimum argument count, maximum argument count, return type)
+        let (min, max, result) = match name {
+            "sin" | "cos" | "asin" | "acos" | "tan" | "atan" | "torad" | "todeg" | "abs" | "floor" | "ceil" | "exp" | "frac" | "log"
+            | "round" | "signum" | "sqrt" => (1, 1, float),
+            "atan2" | "pow" | "fmod" => (2, 2, float),
+            "min" | "max" => (2, usize::MAX, float),
+            "clamp" => (3, 3, float),
+            "random" => (0, 0, float),
+            "smooth" => (1, 4, float),
+            "between" | "equals" => (3, 3, Some(UniformType::Bool)),
+            "in" => (2, usize::MAX, Some(UniformType::Bool)),
+            "vec2" => (2, 2, Some(UniformType::Vec2)),
+            "vec3" => (3, 3, Some(UniformType::Vec3)),
+            "vec4" => (4, 4, Some(UniformType::Vec4)),
+            "if" => {
+                if arguments.len() < 3 || arguments.len().is_multiple_of(2) {
+                    self.report(
+                        start,
+                        end,
+                        DiagnosticSeverity::ERROR,
+                        "Function `if` requires `if(cond, value, [cond2, value2, ...], value_else)`".to_owned(),
+                    );
+                    return None;
+                }
+                let mut value_type = None;
+                for (index, argument) in arguments.iter().enumerate() {
+                    if index % 2 == 0 && index != arguments.len() - 1 {
+                        if argument.is_some_and(|argument| argument != UniformType::Bool) {
+                            self.report(
+                                start,
+                                end,
+                                DiagnosticSeverity::ERROR,
+                                "Conditions of `if` should be bool".to_owned(),
+                            );
+                        }
+                    } else if let Some(argument) = argument {
+                        match value_type {
+                            Some(value_type) if !UniformType::accepts(&value_type, *argument) => {
+                                self.report(
+                                    start,
+                                    end,
+                                    DiagnosticSeverity::ERROR,
+                                    "Values of `if` should have the same type".to_owned(),
+                                );
+                            }
+                            Some(_) => {}
+                            None => value_type = Some(*argument),
+                        }
+                    }
+                }
+                return value_type;
+            }
+            _ => {
+                self.report(start, end, DiagnosticSeverity::WARNING, format!("Unknown function `{}`", name));
+                return None;
+            }
+        };
+        if arguments.len() < min || arguments.len() > max {
+            let message = format!("Function `{}` does not take {} arguments", name, arguments.len());
+            self.report(start, end, DiagnosticSeverity::ERROR, message);
+        } else if !arguments.iter().all(is_float) {
+            self.report(
+                start,
+                end,
+                DiagnosticSeverity::ERROR,
+                format!("Arguments of `{}` should be float", name),
+            );
+        }
+        result
+    }
+}
@@ -0,0 +1,207 @@
+use std::path::{Path, PathBuf};
+
+use hashbrown::HashMap;
+use tower_lsp::lsp_types::*;
+
+use crate::constant::RE_DIMENSION_FOLDER;
+use crate::file::ShaderPack;
+
+mod custom_uniform;
+mod dimension;
+mod expression;
+
+pub use dimension::*;
+pub use expression::*;
+
+/// Value types that custom uniforms and variables can be declared as.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UniformType {
+    Bool,
+    Int,
+    Float,
+    Vec2,
+    Vec3,
+    Vec4,
+}
+
+impl UniformType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bool" => Some(Self::Bool),
+            "int" => Some(Self::Int),
+            "float" => Some(Self::Float),
+            "vec2" => Some(Self::Vec2),
+            "vec3" => Some(Self::Vec3),
+            "vec4" => Some(Self::Vec4),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bool => "bool",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Vec2 => "vec2",
+            Self::Vec3 => "vec3",
+            Self::Vec4 => "vec4",
+        }
+    }
+
+    /// Whether a value of `other` type can be stored into a uniform of this type.
+    /// Optifine evaluates every number as float, so `int` and `float` are interchangeable here.
+    pub fn accepts(&self, other: UniformType) -> bool {
+        match (self, other) {
+            (Self::Int | Self::Float, Self::Int | Self::Float) => true,
+            (left, right) => *left == right,
+        }
+    }
+}
+
+/// A custom uniform or variable declared by `uniform.<type>.<name>` or `variable.<type>.<name>`
+pub struct CustomUniform {
+    pub uniform_type: UniformType,
+    /// False if this is declared as `variable`, which is only visible to other expressions
+    pub is_uniform: bool,
+    /// Range of the key in `shaders.properties`
+    pub range: Range,
+}
+
+#[derive(Default)]
+pub struct ShaderProperties {
+    pub custom_uniforms: HashMap<String, CustomUniform>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A `key = value` line in a properties file, with continuation lines already joined.
+pub struct PropertyEntry<'a> {
+    pub key: &'a str,
+    pub value: String,
+    /// Line and character range of the key
+    pub key_range: Range,
+    /// Positions of each value segment, used to map value offsets back to the document.
+    /// Each item is (offset in value, line, character of the segment start)
+    segments: Vec<(usize, u32, u32)>,
+}
+
+impl PropertyEntry<'_> {
+    /// Convert a byte offset in `value` to a document position
+    pub fn position(&self, offset: usize) -> Position {
+        let (segment_offset, line, character) = self
+            .segments
+            .iter()
+            .rev()
+            .find(|(segment_offset, _, _)| *segment_offset <= offset)
+            .unwrap_or(&self.segments[0]);
+        let chars = self.value.get(*segment_offset..offset).map_or(0, |text| text.chars().count());
+        Position {
+            line: *line,
+            character: character + chars as u32,
+        }
+    }
+
+    pub fn range(&self, start: usize, end: usize) -> Range {
+        Range {
+            start: self.position(start),
+            end: self.position(end),
+        }
+    }
+}
+
+/// Split a java style properties file into entries.
+/// Comment lines and preprocessor lines (both started with `#`) are skipped.
+pub fn parse_properties(content: &str) -> Vec<PropertyEntry<'_>> {
+    let mut entries = vec![];
+    let mut lines = content.lines().enumerate();
+
+    while let Some((line, text)) = lines.next() {
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('!') {
+            continue;
+        }
+        let key_start = text.len() - trimmed.len();
+        let (key, rest) = match trimmed.find(['=', ':']) {
+            Some(index) => (trimmed[..index].trim_end(), &trimmed[index + 1..]),
+            None => continue,
+        };
+        let key_start_char = text[..key_start].chars().count() as u32;
+        let key_range = Range {
+            start: Position {
+                line: line as u32,
+                character: key_start_char,
+            },
+            end: Position {
+                line: line as u32,
+                character: key_start_char + key.chars().count() as u32,
+            },
+        };
+
+        let mut value = String::new();
+        let mut segments = vec![];
+        let value_start = text.len() - rest.trim_start().len();
+        let mut segment = (line, &text[value_start..], text[..value_start].chars().count());
+        loop {
+            let (segment_line, segment_text, segment_character) = segment;
+            segments.push((value.len(), segment_line as u32, segment_character as u32));
+            match segment_text.strip_suffix('\\') {
+                Some(segment_text) => {
+                    value += segment_text;
+                    match lines.next() {
+                        Some((next_line, next_text)) => {
+                            let next_start = next_text.len() - next_text.trim_start().len();
+                            segment = (next_line, &next_text[next_start..], next_text[..next_start].chars().count());
+                        }
+                        None => break,
+                    }
+                }
+                None => {
+                    value += segment_text;
+                    break;
+                }
+            }
+        }
+
+        entries.push(PropertyEntry {
+            key,
+            value,
+            key_range,
+            segments,
+        });
+    }
+    entries
+}
+
+impl ShaderPack {
+    pub fn properties_path(&self) -> PathBuf {
+        self.path.join("shaders.properties")
+    }
+
+    pub fn is_properties_file(&self, file_path: &Path) -> bool {
+        file_path.parent() == Some(&self.path) && file_path.file_name().is_some_and(|name| name == "shaders.properties")
+    }
+
+    /// Reload `shaders.properties` of this pack from disc
+    pub fn update_properties(&self) {
+        let content = self.read_file(&self.properties_path()).unwrap_or_default();
+        *self.properties.borrow_mut() = ShaderProperties::new(&content);
+    }
+
+    pub fn dimension_properties_path(&self) -> PathBuf {
+        self.path.join("dimension.properties")
+    }
+
+    pub fn is_dimension_properties_file(&self, file_path: &Path) -> bool {
+        file_path.parent() == Some(&self.path) && file_path.file_name().is_some_and(|name| name == "dimension.properties")
+    }
+
+    /// Reload `dimension.properties` of this pack from disc
+    pub fn update_dimensions(&self) {
+        let content = self.read_file(&self.dimension_properties_path()).unwrap_or_default();
+        *self.dimensions.borrow_mut() = DimensionProperties::new(&content);
+    }
+
+    /// Folders named `worldN` are dimension folders for OptiFine, Iris also accepts folders from `dimension.properties`.
+    pub fn is_dimension_folder(&self, folder: &str) -> bool {
+        RE_DIMENSION_FOLDER.is_match(folder) || self.dimensions.borrow().folders.contains_key(folder)
+    }
+}
@@ -0,0 +1,161 @@
+use crate::tree_parser::FunctionCalls;
+
+use super::*;
+
+fn function_calls(workspace_file: &WorkspaceFile) -> Vec<FunctionCalls> {
+    TreeParser::function_calls(
+        &workspace_file.tree().borrow(),
+        &workspace_file.content().borrow(),
+        &workspace_file.line_mapping().borrow(),
+    )
+}
+
+/// Shaders whose `main` calls this function directly or indirectly, as paths relative to their packs
+fn reaching_programs(name: &str, workspace_file: &WorkspaceFile) -> Vec<String> {
+    let mut programs = vec![];
+    for (shader_path, (shader_file, _)) in workspace_file.parent_shaders().borrow().iter() {
+        let mut call_graph: HashMap<String, Vec<String>> = HashMap::new();
+        for (_, tree_file) in WorkspaceFile::include_tree(shader_file, shader_path) {
+            for function in function_calls(&tree_file) {
+                call_graph
+                    .entry(function.name)
+                    .or_default()
+                    .extend(function.calls.into_iter().map(|(callee, _)| callee));
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut stack = vec!["main"];
+        while let Some(caller) = stack.pop() {
+            if caller == name {
+                let pack_path = &shader_file.shader_pack().path;
+                let relative_path = shader_path.strip_prefix(pack_path).unwrap_or(shader_path);
+                programs.push(relative_path.to_string_lossy().into_owned());
+                break;
+            }
+            if visited.insert(caller) {
+                if let Some(callees) = call_graph.get(caller) {
+                    stack.extend(callees.iter().map(String::as_str));
+                }
+            }
+        }
+    }
+    programs.sort_unstable();
+    programs
+}
+
+/// Call hierarchy item of a function definition, detailed with programs reaching it
+fn function_item(function: &FunctionCalls, file_path: &Path, workspace_file: &WorkspaceFile) -> CallHierarchyItem {
+    let programs = reaching_programs(&function.name, workspace_file);
+    CallHierarchyItem {
+        name: function.name.clone(),
+        kind: SymbolKind::FUNCTION,
+        tags: None,
+        detail: (!programs.is_empty()).then(|| "Reached from ".to_owned() + &programs.join(", ")),
+        uri: path_to_url(file_path),
+        range: function.range,
+        selection_range: function.selection_range,
+        data: None,
+    }
+}
+
+impl MinecraftLanguageServer {
+    pub fn prepare_call_hierarchy(&self, params: CallHierarchyPrepareParams) -> Option<Vec<CallHierarchyItem>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+
+        let file_path = url_to_path(&params.text_document_position_params.text_document.uri);
+        let (file_path, workspace_file) = workspace_files.get_key_value(&file_path)?;
+        let position = params.text_document_position_params.position;
+        let contains = |range: &Range| range.start <= position && position <= range.end;
+
+        let functions = function_calls(workspace_file);
+        if let Some(function) = functions.iter().find(|function| contains(&function.selection_range)) {
+            return Some(vec![function_item(function, file_path, workspace_file)]);
+        }
+        let (name, _) = functions
+            .iter()
+            .flat_map(|function| &function.calls)
+            .find(|(_, range)| contains(range))?;
+
+        // Every definition the call may resolve to, like variants in different preprocessor branches
+        let mut items = vec![];
+        for (tree_path, tree_file) in Self::visible_files(file_path, workspace_file) {
+            for function in function_calls(&tree_file) {
+                if function.name == *name {
+                    items.push(function_item(&function, &tree_path, &tree_file));
+                }
+            }
+        }
+        (!items.is_empty()).then_some(items)
+    }
+
+    pub fn incoming_calls(&self, params: CallHierarchyIncomingCallsParams) -> Option<Vec<CallHierarchyIncomingCall>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+
+        let item = params.item;
+        let file_path = url_to_path(&item.uri);
+        let (file_path, workspace_file) = workspace_files.get_key_value(&file_path)?;
+
+        let mut incoming_calls = vec![];
+        for (tree_path, tree_file) in Self::visible_files(file_path, workspace_file) {
+            for function in function_calls(&tree_file) {
+                let from_ranges = function
+                    .calls
+                    .iter()
+                    .filter(|(callee, _)| *callee == item.name)
+                    .map(|(_, range)| *range)
+                    .collect::<Vec<_>>();
+                if !from_ranges.is_empty() {
+                    incoming_calls.push(CallHierarchyIncomingCall {
+                        from: function_item(&function, &tree_path, &tree_file),
+                        from_ranges,
+                    });
+                }
+            }
+        }
+        Some(incoming_calls)
+    }
+
+    pub fn outgoing_calls(&self, params: CallHierarchyOutgoingCallsParams) -> Option<Vec<CallHierarchyOutgoingCall>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+
+        let item = params.item;
+        let file_path = url_to_path(&item.uri);
+        let (file_path, workspace_file) = workspace_files.get_key_value(&file_path)?;
+        let function = function_calls(workspace_file)
+            .into_iter()
+            .find(|function| function.selection_range == item.selection_range)?;
+
+        // Call sites grouped by callees in order of their first calls
+        let mut callees: Vec<(String, Vec<Range>)> = vec![];
+        for (callee, range) in function.calls {
+            match callees.iter_mut().find(|(name, _)| *name == callee) {
+                Some((_, ranges)) => ranges.push(range),
+                None => callees.push((callee, vec![range])),
+            }
+        }
+
+        let mut definitions = vec![];
+        for (tree_path, tree_file) in Self::visible_files(file_path, workspace_file) {
+            for function in function_calls(&tree_file) {
+                if callees.iter().any(|(callee, _)| *callee == function.name) {
+                    definitions.push(function_item(&function, &tree_path, &tree_file));
+                }
+            }
+        }
+        let mut outgoing_calls = vec![];
+        for (callee, from_ranges) in callees {
+            // Built-in functions and macros have no definitions
+            for definition in definitions.iter().filter(|definition| definition.name == callee) {
+                outgoing_calls.push(CallHierarchyOutgoingCall {
+                    to: definition.clone(),
+                    from_ranges: from_ranges.clone(),
+                });
+            }
+        }
+        Some(outgoing_calls)
+    }
+}
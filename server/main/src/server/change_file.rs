@@ -2,7 +2,7 @@ use super::*;
 
 impl MinecraftLanguageServer {
     pub fn change_file(&self, url: Url, changes: Vec<TextDocumentContentChangeEvent>) -> Option<Diagnostics> {
-        let file_path = url.to_file_path().unwrap();
+        let file_path = url_to_path(&url);
 
         let server_data = self.server_data.lock().unwrap();
         let mut parser = server_data.tree_sitter_parser.borrow_mut();
@@ -2,12 +2,13 @@ use super::*;
 
 impl MinecraftLanguageServer {
     pub fn close_file(&self, file_url: Url) -> Option<Diagnostics> {
-        let file_path = file_url.to_file_path().unwrap();
+        let file_path = url_to_path(&file_url);
 
         let server_data = self.server_data.lock().unwrap();
         let mut parser = server_data.tree_sitter_parser.borrow_mut();
         let mut workspace_files = server_data.workspace_files.borrow_mut();
         let mut temp_files = server_data.temp_files.borrow_mut();
+        server_data.semantic_tokens.borrow_mut().remove(&file_path);
 
         // Force closing may result in temp changes discarded, so the content should reset to the disc copy.
         let diagnostics = if let Some((file_path, workspace_file)) = workspace_files.get_key_value(&file_path) {
@@ -0,0 +1,209 @@
+use crate::builtin::{builtins_named, BuiltinKind, KEYWORDS};
+
+use super::rename_files::abstract_include_path;
+use super::*;
+
+/// Diagnostics on lines of this range mentioning this name, which are resolved by a fix there
+fn matching_diagnostics(diagnostics: &[Diagnostic], range: Range, name: &str) -> Vec<Diagnostic> {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            diagnostic.range.start.line <= range.end.line
+                && range.start.line <= diagnostic.range.end.line
+                && diagnostic.message.contains(name)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Add diagnostics not added yet, as multiple fixed places may be on the same line
+fn extend_unique(fixed_diagnostics: &mut Vec<Diagnostic>, diagnostics: Vec<Diagnostic>) {
+    for diagnostic in diagnostics {
+        if !fixed_diagnostics.contains(&diagnostic) {
+            fixed_diagnostics.push(diagnostic);
+        }
+    }
+}
+
+fn quick_fix(title: String, url: &Url, edits: Vec<TextEdit>, diagnostics: Vec<Diagnostic>) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(!diagnostics.is_empty()),
+        diagnostics: (!diagnostics.is_empty()).then_some(diagnostics),
+        edit: Some(WorkspaceEdit {
+            changes: Some(std::collections::HashMap::from([(url.clone(), edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    })
+}
+
+fn insert_edit(line: u32, new_text: String) -> TextEdit {
+    TextEdit {
+        range: Range::new(Position::new(line, 0), Position::new(line, 0)),
+        new_text,
+    }
+}
+
+impl MinecraftLanguageServer {
+    /// Declarations of OptiFine uniforms and includes of functions used but not defined in `rows` of this file
+    fn missing_definition_fixes(
+        url: &Url, file_path: &Rc<PathBuf>, workspace_file: &Rc<WorkspaceFile>, workspace_files: &HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>,
+        rows: std::ops::Range<usize>, diagnostics: &[Diagnostic], actions: &mut Vec<CodeActionOrCommand>,
+    ) {
+        let content = workspace_file.content().borrow();
+        let visible_files = Self::visible_files(file_path, workspace_file);
+        let is_defined = |name: &str| {
+            visible_files
+                .iter()
+                .any(|(_, tree_file)| TreeParser::defines(name, &tree_file.tree().borrow(), &tree_file.content().borrow()))
+        };
+        let insert_line = TreeParser::declaration_line(&content);
+        let shader_pack = workspace_file.shader_pack();
+
+        let unresolved_names = TreeParser::unresolved_names(
+            &workspace_file.tree().borrow(),
+            &content,
+            &workspace_file.line_mapping().borrow(),
+            rows,
+        );
+        let mut fixed_names = HashSet::new();
+        for unresolved in unresolved_names {
+            let name = unresolved.name.as_str();
+            if fixed_names.contains(name) || is_defined(name) {
+                continue;
+            }
+            let builtins = builtins_named(name);
+            let diagnostics = matching_diagnostics(diagnostics, unresolved.range, name);
+            if !unresolved.is_call {
+                // Names shared with GLSL built-ins need no declarations
+                let uniform = match builtins {
+                    [uniform] if uniform.kind == BuiltinKind::Uniform => uniform,
+                    _ => continue,
+                };
+                let declaration = uniform.signatures[0];
+                let edit = insert_edit(insert_line, declaration.to_owned() + ";\n");
+                actions.push(quick_fix(format!("Declare `{}`", declaration), url, vec![edit], diagnostics));
+            } else if builtins.is_empty() && !KEYWORDS.contains(&name) {
+                let mut include_paths = workspace_files
+                    .iter()
+                    .filter(|(_, include_file)| {
+                        Rc::ptr_eq(include_file.shader_pack(), shader_pack)
+                            && *include_file.file_type().borrow() == gl::NONE
+                            && TreeParser::defines(name, &include_file.tree().borrow(), &include_file.content().borrow())
+                    })
+                    .filter_map(|(include_path, _)| abstract_include_path(&shader_pack.path, include_path).ok())
+                    .collect::<Vec<_>>();
+                include_paths.sort_unstable();
+                for include_path in include_paths {
+                    let edit = insert_edit(insert_line, format!("#include \"{}\"\n", include_path));
+                    let title = format!("Add `#include \"{}\"` for `{}`", include_path, name);
+                    actions.push(quick_fix(title, url, vec![edit], diagnostics.clone()));
+                }
+            } else {
+                continue;
+            }
+            fixed_names.insert(name.to_owned());
+        }
+    }
+
+    pub fn code_action(&self, params: CodeActionParams) -> Option<CodeActionResponse> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let url = params.text_document.uri;
+        let file_path = url_to_path(&url);
+        let workspace_file = workspace_files.get_key_value(&file_path);
+        let file: &dyn ShaderFile = match workspace_file {
+            // Files in zipped shader packs are read-only
+            Some((_, workspace_file)) if workspace_file.shader_pack().archive.is_some() => return None,
+            Some((_, workspace_file)) => workspace_file as &WorkspaceFile,
+            None => temp_files.get(&file_path)?,
+        };
+
+        let rows = params.range.start.line as usize..params.range.end.line as usize + 1;
+        let in_rows = |range: &Range| rows.contains(&(range.start.line as usize));
+        let diagnostics = params.context.diagnostics.as_slice();
+        let mut actions = vec![];
+
+        // Other files are only known through the include graph of workspace files
+        if let Some((file_path, workspace_file)) = workspace_file {
+            Self::missing_definition_fixes(
+                &url,
+                file_path,
+                workspace_file,
+                &workspace_files,
+                rows.clone(),
+                diagnostics,
+                &mut actions,
+            );
+        }
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+        // Replacements only compile from the versions that introduced them
+        let version_number = match workspace_file {
+            Some((_, workspace_file)) => Self::glsl_version(workspace_file),
+            None => GlslVersion::parse(&content),
+        }
+        .map_or(0, |version| version.number);
+
+        let texture_edits = if version_number >= 130 {
+            TreeParser::legacy_texture_edits(&tree, &content, &line_mapping)
+        } else {
+            vec![]
+        };
+        if texture_edits.iter().any(|(_, edit)| in_rows(&edit.range)) {
+            let mut fixed_diagnostics = vec![];
+            for (legacy_name, edit) in &texture_edits {
+                extend_unique(&mut fixed_diagnostics, matching_diagnostics(diagnostics, edit.range, legacy_name));
+            }
+            let edits = texture_edits.into_iter().map(|(_, edit)| edit).collect();
+            actions.push(quick_fix(
+                "Replace legacy texture functions with core profile equivalents".to_owned(),
+                &url,
+                edits,
+                fixed_diagnostics,
+            ));
+        }
+
+        // The first edit inserts declarations of outputs
+        if let Some(edits) = (version_number >= 330)
+            .then(|| TreeParser::fragment_data_edits(&tree, &content, &line_mapping))
+            .flatten()
+        {
+            if edits[1..].iter().any(|edit| in_rows(&edit.range)) {
+                let mut fixed_diagnostics = vec![];
+                for edit in &edits[1..] {
+                    for name in ["gl_FragData", "gl_FragColor"] {
+                        extend_unique(&mut fixed_diagnostics, matching_diagnostics(diagnostics, edit.range, name));
+                    }
+                }
+                actions.push(quick_fix(
+                    "Replace `gl_FragData` and `gl_FragColor` with output variables".to_owned(),
+                    &url,
+                    edits,
+                    fixed_diagnostics,
+                ));
+            }
+        }
+
+        for edit in TreeParser::render_targets_edits(&tree, &content, &line_mapping) {
+            if in_rows(&edit.range) {
+                let fixed_diagnostics = matching_diagnostics(diagnostics, edit.range, "DRAWBUFFERS");
+                actions.push(quick_fix(
+                    "Convert `DRAWBUFFERS` to `RENDERTARGETS`".to_owned(),
+                    &url,
+                    vec![edit],
+                    fixed_diagnostics,
+                ));
+            }
+        }
+
+        (!actions.is_empty()).then_some(actions)
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+impl MinecraftLanguageServer {
+    pub fn code_lens(&self, params: CodeLensParams) -> Option<Vec<CodeLens>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let programs = server_data.programs.borrow();
+
+        let file_path = url_to_path(&params.text_document.uri);
+        let workspace_file = workspace_files.get(&file_path)?;
+        let file_type = *workspace_file.file_type().borrow();
+        if file_type == gl::NONE || file_type == gl::INVALID_ENUM {
+            return None;
+        }
+
+        let shader_pack = workspace_file.shader_pack();
+        let (dimension, program) = program_of_file(&programs, &shader_pack.path, &file_path)?;
+        let fallback_trees = FallbackTree::build(shader_pack, &programs, workspace_files.iter());
+        let mut code_lens = vec![];
+        // Lenses are labels only, an empty command id makes clients show them without running anything
+        let mut push_code_lens = |title: String| {
+            code_lens.push(CodeLens {
+                range: Range::default(),
+                command: Some(Command {
+                    title,
+                    command: String::new(),
+                    arguments: None,
+                }),
+                data: None,
+            })
+        };
+
+        // Files in dimension folders take the place of base programs
+        if let Some(dimension) = dimension {
+            let base_fallback = fallback_trees
+                .iter()
+                .find(|tree| tree.dimension.is_none())
+                .and_then(|base_tree| base_tree.programs.iter().find(|fallback| fallback.program == program));
+            let base_program = match base_fallback {
+                Some(fallback) => fallback.used_program.clone(),
+                None => collect_programs(shader_pack, &programs, workspace_files.iter())
+                    .contains_key(&(None, program.to_owned()))
+                    .then(|| program.to_owned()),
+            };
+            let mut title = match base_program {
+                Some(base_program) if base_program == program => format!("Overrides {} in base folder", program),
+                Some(base_program) => format!("Overrides {} in base folder, which {} falls back to", base_program, program),
+                None => format!("{} is only enabled in this dimension", program),
+            };
+            if let Some(dimension_ids) = shader_pack.dimensions.borrow().folders.get(dimension) {
+                title += " for ";
+                title += &dimension_ids.join(", ");
+            }
+            push_code_lens(title);
+        }
+
+        // Programs falling back to this one share this file
+        let also_used_for = fallback_trees
+            .iter()
+            .find(|tree| tree.dimension.as_deref() == dimension)
+            .map_or(vec![], |fallback_tree| fallback_tree.also_used_for(program))
+            .into_iter()
+            .map(|fallback| match fallback.loaders.as_slice() {
+                [Loader::OptiFine] => fallback.program.clone() + " (OptiFine)",
+                [Loader::Iris] => fallback.program.clone() + " (Iris)",
+                _ => fallback.program.clone(),
+            })
+            .collect::<Vec<_>>();
+        if !also_used_for.is_empty() {
+            push_code_lens("Also used for: ".to_owned() + &also_used_for.join(", "));
+        }
+
+        (!code_lens.is_empty()).then_some(code_lens)
+    }
+}
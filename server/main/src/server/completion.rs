@@ -0,0 +1,184 @@
+use crate::builtin::{builtins, BuiltinKind, KEYWORDS};
+
+use super::*;
+
+/// Files and folders to complete in `#include` or `#moj_import` directives, `typed` is the path before the cursor
+fn include_path_completion(
+    shader_pack: &ShaderPack, file_path: &Path, directive: &str, typed: &str, extensions: &HashSet<String>, position: Position,
+) -> Vec<CompletionItem> {
+    let (folder, prefix) = match directive {
+        "include" => {
+            let (folder, prefix) = typed.rsplit_once('/').map_or(("", typed), |(folder, prefix)| (folder, prefix));
+            // A leading `/` means the pack root, while an empty folder means the folder of this file
+            let folder = match (folder, typed.starts_with('/')) {
+                ("", true) => "/.".to_owned(),
+                ("", false) => ".".to_owned(),
+                _ => folder.to_owned() + "/.",
+            };
+            match include_path_join(&shader_pack.path, file_path, &folder) {
+                Ok(path) => (path, prefix),
+                Err(_) => return vec![],
+            }
+        }
+        _ => {
+            let typed = typed.split_once(':').map_or(typed, |(_, path)| path);
+            let (folder, prefix) = typed.rsplit_once('/').map_or(("", typed), |(folder, prefix)| (folder, prefix));
+            (moj_import_path(&shader_pack.path, folder), prefix)
+        }
+    };
+
+    let range = Range {
+        start: Position {
+            line: position.line,
+            character: position.character - prefix.chars().count() as u32,
+        },
+        end: position,
+    };
+    let (files, folders) = shader_pack.list_folder(&folder);
+    let folders = folders.into_iter().filter_map(|path| {
+        let name = path.file_name()?.to_str()?.to_owned();
+        Some(CompletionItem {
+            label: name.clone(),
+            kind: Some(CompletionItemKind::FOLDER),
+            sort_text: Some("0".to_owned() + &name),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range,
+                new_text: name + "/",
+            })),
+            // Continue completing files inside this folder
+            command: Some(Command {
+                title: String::new(),
+                command: "editor.action.triggerSuggest".to_owned(),
+                arguments: None,
+            }),
+            ..Default::default()
+        })
+    });
+    let files = files
+        .into_iter()
+        .filter(|path| path != file_path)
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| extensions.contains(extension))
+        })
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?.to_owned();
+            Some(CompletionItem {
+                label: name.clone(),
+                kind: Some(CompletionItemKind::FILE),
+                sort_text: Some("1".to_owned() + &name),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit { range, new_text: name })),
+                ..Default::default()
+            })
+        });
+    folders.chain(files).filter(|item| !item.label.starts_with('.')).collect()
+}
+
+impl MinecraftLanguageServer {
+    pub fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+        let programs = server_data.programs.borrow();
+
+        let file_path = url_to_path(&params.text_document_position.text_document.uri);
+        let position = params.text_document_position.position;
+
+        let workspace_file = workspace_files.get_key_value(&file_path);
+        let file: &dyn ShaderFile = if let Some((_, workspace_file)) = workspace_file {
+            workspace_file as &WorkspaceFile
+        } else {
+            temp_files.get(&file_path)?
+        };
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+
+        let offset = byte_index(&content, position, &line_mapping).0;
+        let line_start = line_mapping[position.line as usize];
+        if let Some(captures) = RE_INCLUDE_PATH_PREFIX.captures(&content[line_start..offset]) {
+            let shader_pack: &ShaderPack = match workspace_file {
+                Some((_, workspace_file)) => workspace_file.shader_pack(),
+                None if *file.file_type().borrow() != gl::INVALID_ENUM => temp_files.get(&file_path)?.shader_pack(),
+                None => return None,
+            };
+            let items = include_path_completion(
+                shader_pack,
+                &file_path,
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+                &server_data.extensions.borrow(),
+                position,
+            );
+            return Some(CompletionResponse::Array(items));
+        }
+        // Trigger characters of paths are also operators in code
+        if params
+            .context
+            .is_some_and(|context| context.trigger_kind == CompletionTriggerKind::TRIGGER_CHARACTER)
+        {
+            return None;
+        }
+
+        let mut items = TreeParser::local_completion(position, &tree, &content, &line_mapping)?;
+        let shader = if let Some((file_path, workspace_file)) = workspace_file {
+            for (_, tree_file) in Self::visible_files(file_path, workspace_file) {
+                items.extend(TreeParser::global_completion(
+                    &tree_file.tree().borrow(),
+                    &tree_file.content().borrow(),
+                ));
+            }
+            Self::shader_program(&programs, workspace_file, file_path)
+        } else {
+            items.extend(TreeParser::global_completion(&tree, &content));
+            None
+        };
+        let shader = shader.map(|(program, stage)| (program.name.as_str(), stage));
+        let version = match workspace_file {
+            Some((_, workspace_file)) => Self::glsl_version(workspace_file),
+            None => GlslVersion::parse(&content),
+        };
+
+        // Inner scopes come first, so they shadow definitions with the same name outside
+        let mut labels = HashSet::new();
+        items.retain(|item| labels.insert(item.label.clone()));
+
+        // Uniforms declared in code are already listed with their declarations
+        items.extend(
+            builtins()
+                .filter(|builtin| !labels.contains(builtin.name))
+                .filter(|builtin| builtin.is_available(shader, version))
+                .map(|builtin| {
+                    let kind = match builtin.kind {
+                        BuiltinKind::Function => CompletionItemKind::FUNCTION,
+                        _ => CompletionItemKind::VARIABLE,
+                    };
+                    let mut detail = builtin.signatures[0].to_owned();
+                    if builtin.signatures.len() > 1 {
+                        detail += &format!(" (+{} overloads)", builtin.signatures.len() - 1);
+                    }
+                    CompletionItem {
+                        label: builtin.name.to_owned(),
+                        kind: Some(kind),
+                        detail: Some(detail),
+                        documentation: Some(Documentation::MarkupContent(MarkupContent {
+                            kind: MarkupKind::Markdown,
+                            value: builtin.documentation(),
+                        })),
+                        sort_text: Some("2".to_owned() + builtin.name),
+                        ..Default::default()
+                    }
+                }),
+        );
+        items.extend(KEYWORDS.iter().map(|keyword| CompletionItem {
+            label: keyword.to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            sort_text: Some("3".to_owned() + keyword),
+            ..Default::default()
+        }));
+
+        Some(CompletionResponse::Array(items))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::tree_parser::{OutParameters, TypeTable};
+
+use super::*;
+
+impl MinecraftLanguageServer {
+    pub fn document_highlight(&self, params: DocumentHighlightParams) -> Option<Vec<DocumentHighlight>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(&params.text_document_position_params.text_document.uri);
+
+        // Struct types and function parameters may come from other files in the include graph
+        let mut types = TypeTable::default();
+        let mut out_parameters = OutParameters::default();
+        let file: &dyn ShaderFile = if let Some((file_path, workspace_file)) = workspace_files.get_key_value(&file_path) {
+            for (_, tree_file) in Self::visible_files(file_path, workspace_file) {
+                let tree = tree_file.tree().borrow();
+                let content = tree_file.content().borrow();
+                types.extend(&tree, &content);
+                out_parameters.extend(&tree, &content);
+            }
+            workspace_file as &WorkspaceFile
+        } else {
+            let temp_file = temp_files.get(&file_path)?;
+            types.extend(&temp_file.tree().borrow(), &temp_file.content().borrow());
+            out_parameters.extend(&temp_file.tree().borrow(), &temp_file.content().borrow());
+            temp_file
+        };
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+
+        let position = params.text_document_position_params.position;
+        let symbol = TreeParser::symbol_at(position, &tree, &content, &line_mapping, &types)?;
+        let highlights = TreeParser::document_highlights(&symbol, &tree, &content, &line_mapping, &types, &out_parameters);
+        (!highlights.is_empty()).then_some(highlights)
+    }
+}
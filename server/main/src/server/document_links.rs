@@ -2,7 +2,7 @@ use super::*;
 
 impl MinecraftLanguageServer {
     pub fn document_links(&self, url: Url) -> Option<(Vec<DocumentLink>, Diagnostics)> {
-        let file_path = url.to_file_path().unwrap();
+        let file_path = url_to_path(&url);
 
         let server_data = self.server_data.lock().unwrap();
         let workspace_files = server_data.workspace_files.borrow();
@@ -12,6 +12,24 @@ impl LanguageServerError {
         }
     }
 
+    #[inline]
+    pub fn not_archive_file_error() -> Error {
+        Error {
+            code: ErrorCode::ServerError(-20003),
+            message: "This file is not in a zipped shader pack".to_owned(),
+            data: None,
+        }
+    }
+
+    #[inline]
+    pub fn invalid_rename_error(message: String) -> Error {
+        Error {
+            code: ErrorCode::ServerError(-20004),
+            message,
+            data: None,
+        }
+    }
+
     #[inline]
     pub fn invalid_command_error() -> Error {
         Error {
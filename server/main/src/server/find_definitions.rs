@@ -1,29 +1,131 @@
 use super::*;
 
+/// Global definitions with this name in a workspace file, optionally only those before a line
+fn push_definitions(name: &str, file_path: &Path, workspace_file: &WorkspaceFile, before_line: Option<u32>, locations: &mut Vec<Location>) {
+    let definitions = TreeParser::named_definitions(
+        name,
+        &workspace_file.tree().borrow(),
+        &workspace_file.content().borrow(),
+        &workspace_file.line_mapping().borrow(),
+    );
+    let url = path_to_url(file_path);
+    locations.extend(
+        definitions
+            .into_iter()
+            .filter(|definition| before_line.is_none_or(|line| definition.range.start.line < line))
+            .map(|definition| Location {
+                uri: url.clone(),
+                range: definition.range,
+            }),
+    );
+}
+
+/// Search definitions visible before `stop_path` is included, or before `end_line` of this file.
+///
+/// Files included earlier are searched entirely, returns true if `stop_path` is reached.
+fn search_preceding(
+    name: &str, file_path: &Path, workspace_file: &WorkspaceFile, stop_path: Option<&Path>, end_line: Option<u32>, depth: u8,
+    locations: &mut Vec<Location>,
+) -> bool {
+    if depth > 10 {
+        return false;
+    }
+    let including_files = workspace_file.including_files().borrow();
+    for (line, _, _, include_path, include_file) in including_files.iter() {
+        let line = *line as u32;
+        if end_line.is_some_and(|end_line| line >= end_line) {
+            break;
+        }
+        if *include_file.file_type().borrow() == gl::INVALID_ENUM {
+            continue;
+        }
+        if stop_path == Some(include_path.as_path())
+            || search_preceding(name, include_path, include_file, stop_path, None, depth + 1, locations)
+        {
+            push_definitions(name, file_path, workspace_file, Some(line), locations);
+            return true;
+        }
+    }
+    push_definitions(name, file_path, workspace_file, end_line, locations);
+    false
+}
+
 impl MinecraftLanguageServer {
     pub fn find_definitions(&self, params: GotoDeclarationParams) -> Option<Vec<Location>> {
         let server_data = self.server_data.lock().unwrap();
         let workspace_files = server_data.workspace_files.borrow();
         let temp_files = server_data.temp_files.borrow();
 
-        let file_path = params.text_document_position_params.text_document.uri.to_file_path().unwrap();
+        let file_path = url_to_path(&params.text_document_position_params.text_document.uri);
 
-        let file: &dyn ShaderFile = if let Some(workspace_file) = workspace_files.get(&file_path) {
-            workspace_file as &WorkspaceFile
+        let (file, shader_pack): (&dyn ShaderFile, &ShaderPack) = if let Some(workspace_file) = workspace_files.get(&file_path) {
+            (workspace_file as &WorkspaceFile, workspace_file.shader_pack())
         } else {
-            temp_files.get(&file_path)?
+            let temp_file = temp_files.get(&file_path)?;
+            (temp_file, temp_file.shader_pack())
         };
 
         let content = file.content().borrow();
         let tree = file.tree().borrow();
         let line_mapping = file.line_mapping().borrow();
+        let position = params.text_document_position_params.position;
 
-        TreeParser::find_definitions(
+        // Custom uniforms are defined in shaders.properties
+        if let Some(name) = TreeParser::uniform_declaration_name(position, &tree, &content, &line_mapping) {
+            if let Some(custom_uniform) = shader_pack.properties.borrow().custom_uniforms.get(name) {
+                return Some(vec![Location {
+                    uri: path_to_url(&shader_pack.properties_path()),
+                    range: custom_uniform.range,
+                }]);
+            }
+        }
+
+        let mut locations = TreeParser::find_definitions(
             &params.text_document_position_params.text_document.uri,
-            params.text_document_position_params.position,
+            position,
             &tree,
             &content,
             &line_mapping,
         )
+        .unwrap_or_default();
+
+        let (file_path, workspace_file) = match workspace_files.get_key_value(&file_path) {
+            Some(workspace_file) => workspace_file,
+            None => return (!locations.is_empty()).then_some(locations),
+        };
+        let (name, range, local_definition) = match TreeParser::identifier_definition(position, &tree, &content, &line_mapping) {
+            Some(identifier) => identifier,
+            None => return (!locations.is_empty()).then_some(locations),
+        };
+        if let Some(local_definition) = local_definition {
+            return Some(vec![Location {
+                uri: params.text_document_position_params.text_document.uri,
+                range: local_definition.range,
+            }]);
+        }
+
+        // Definitions in files included before this line
+        search_preceding(&name, file_path, workspace_file, None, Some(range.start.line), 0, &mut locations);
+        // Include files see the content of their shaders before they are included
+        let mut parent_shaders = workspace_file
+            .parent_shaders()
+            .borrow()
+            .iter()
+            .filter(|(shader_path, _)| *shader_path != file_path)
+            .map(|(shader_path, (shader_file, _))| (shader_path.clone(), shader_file.clone()))
+            .collect::<Vec<_>>();
+        parent_shaders.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        for (shader_path, shader_file) in &parent_shaders {
+            search_preceding(&name, shader_path, shader_file, Some(file_path), None, 0, &mut locations);
+        }
+
+        // The same file may be searched through multiple shaders
+        let mut unique_locations = vec![];
+        for location in locations {
+            if !unique_locations.contains(&location) {
+                unique_locations.push(location);
+            }
+        }
+        (!unique_locations.is_empty()).then_some(unique_locations)
     }
 }
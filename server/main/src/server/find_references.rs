@@ -1,29 +1,103 @@
+use crate::tree_parser::{Reference, Symbol, TypeTable};
+
 use super::*;
 
+/// References of a symbol grouped by files
+pub(super) type FileReferences = Vec<(Url, Vec<Reference>)>;
+
 impl MinecraftLanguageServer {
+    /// The symbol at this position, with its references in every file that can see it through the include graph
+    pub(super) fn symbol_references(
+        file_path: &PathBuf, position: Position, workspace_files: &HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>,
+        temp_files: &HashMap<PathBuf, TempFile>,
+    ) -> Option<(Symbol, FileReferences)> {
+        let (file_path, workspace_file) = match workspace_files.get_key_value(file_path) {
+            Some(workspace_file) => workspace_file,
+            None => {
+                let temp_file = temp_files.get(file_path)?;
+                let content = temp_file.content().borrow();
+                let tree = temp_file.tree().borrow();
+                let line_mapping = temp_file.line_mapping().borrow();
+
+                let mut types = TypeTable::default();
+                types.extend(&tree, &content);
+                let symbol = TreeParser::symbol_at(position, &tree, &content, &line_mapping, &types)?;
+                let references = TreeParser::symbol_references(&symbol, &tree, &content, &line_mapping, &types);
+                return Some((symbol, vec![(path_to_url(file_path), references)]));
+            }
+        };
+
+        let mut files = Self::visible_files(file_path, workspace_file);
+        let mut types = TypeTable::default();
+        for (_, tree_file) in &files {
+            types.extend(&tree_file.tree().borrow(), &tree_file.content().borrow());
+        }
+        let symbol = TreeParser::symbol_at(
+            position,
+            &workspace_file.tree().borrow(),
+            &workspace_file.content().borrow(),
+            &workspace_file.line_mapping().borrow(),
+            &types,
+        )?;
+
+        match symbol.global_name() {
+            // Shaders including the defining files may not include this file
+            Some(name) => {
+                let defining_files = files
+                    .iter()
+                    .filter(|(_, tree_file)| TreeParser::defines(name, &tree_file.tree().borrow(), &tree_file.content().borrow()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                for (defining_path, defining_file) in &defining_files {
+                    for (tree_path, tree_file) in Self::visible_files(defining_path, defining_file) {
+                        if !files.iter().any(|(path, _)| *path == tree_path) {
+                            types.extend(&tree_file.tree().borrow(), &tree_file.content().borrow());
+                            files.push((tree_path, tree_file));
+                        }
+                    }
+                }
+            }
+            None => files = vec![(file_path.clone(), workspace_file.clone())],
+        }
+
+        let references = files
+            .iter()
+            .map(|(tree_path, tree_file)| {
+                let references = TreeParser::symbol_references(
+                    &symbol,
+                    &tree_file.tree().borrow(),
+                    &tree_file.content().borrow(),
+                    &tree_file.line_mapping().borrow(),
+                    &types,
+                );
+                (path_to_url(tree_path), references)
+            })
+            .filter(|(_, references)| !references.is_empty())
+            .collect();
+        Some((symbol, references))
+    }
+
     pub fn find_references(&self, params: ReferenceParams) -> Option<Vec<Location>> {
         let server_data = self.server_data.lock().unwrap();
         let workspace_files = server_data.workspace_files.borrow();
         let temp_files = server_data.temp_files.borrow();
 
-        let file_path = params.text_document_position.text_document.uri.to_file_path().unwrap();
-
-        let file: &dyn ShaderFile = if let Some(workspace_file) = workspace_files.get(&file_path) {
-            workspace_file as &WorkspaceFile
-        } else {
-            temp_files.get(&file_path)?
-        };
+        let file_path = url_to_path(&params.text_document_position.text_document.uri);
+        let include_declaration = params.context.include_declaration;
 
-        let content = file.content().borrow();
-        let tree = file.tree().borrow();
-        let line_mapping = file.line_mapping().borrow();
-
-        TreeParser::find_references(
-            &params.text_document_position.text_document.uri,
-            params.text_document_position.position,
-            &tree,
-            &content,
-            &line_mapping,
-        )
+        let (_, references) = Self::symbol_references(&file_path, params.text_document_position.position, &workspace_files, &temp_files)?;
+        let locations = references
+            .into_iter()
+            .flat_map(|(url, references)| {
+                references
+                    .into_iter()
+                    .filter(|reference| include_declaration || !reference.is_definition)
+                    .map(move |reference| Location {
+                        uri: url.clone(),
+                        range: reference.range,
+                    })
+            })
+            .collect::<Vec<_>>();
+        (!locations.is_empty()).then_some(locations)
     }
 }
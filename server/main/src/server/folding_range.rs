@@ -0,0 +1,23 @@
+use super::*;
+
+impl MinecraftLanguageServer {
+    pub fn folding_range(&self, params: FoldingRangeParams) -> Option<Vec<FoldingRange>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(&params.text_document.uri);
+
+        let file: &dyn ShaderFile = if let Some(workspace_file) = workspace_files.get(&file_path) {
+            workspace_file as &WorkspaceFile
+        } else {
+            temp_files.get(&file_path)?
+        };
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+
+        Some(TreeParser::folding_ranges(&tree, &content, &line_mapping))
+    }
+}
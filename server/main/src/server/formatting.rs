@@ -0,0 +1,87 @@
+use std::ops::Range as LineRange;
+
+use super::*;
+
+impl MinecraftLanguageServer {
+    /// Edit replacing lines in `rows` of this file with their formatted text, empty if nothing changes
+    fn format_lines(&self, url: &Url, options: &FormattingOptions, rows: Option<LineRange<usize>>) -> Option<Vec<TextEdit>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(url);
+        let file: &dyn ShaderFile = if let Some(workspace_file) = workspace_files.get(&file_path) {
+            // Files in zipped shader packs are read-only
+            if workspace_file.shader_pack().archive.is_some() {
+                return None;
+            }
+            workspace_file as &WorkspaceFile
+        } else {
+            temp_files.get(&file_path)?
+        };
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+
+        let row_count = line_mapping.len() - 1;
+        let rows = rows.map_or(0..row_count, |rows| rows.start..rows.end.min(row_count));
+        if rows.is_empty() {
+            return None;
+        }
+        let indent_unit = if options.insert_spaces {
+            " ".repeat(options.tab_size as usize)
+        } else {
+            "\t".to_owned()
+        };
+        let formatted = TreeParser::format(
+            &tree,
+            &content,
+            &line_mapping,
+            &server_data.format_style.borrow(),
+            &indent_unit,
+            rows.clone(),
+        );
+
+        let start = line_mapping[rows.start];
+        let last_row = rows.end - 1;
+        let mut end = line_mapping[last_row + 1] - 1;
+        if content[..end].ends_with('\r') {
+            end -= 1;
+        }
+        if content[start..end] == formatted {
+            return Some(vec![]);
+        }
+        Some(vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: rows.start as u32,
+                    character: 0,
+                },
+                end: Position {
+                    line: last_row as u32,
+                    character: content[line_mapping[last_row]..end].chars().count() as u32,
+                },
+            },
+            new_text: formatted,
+        }])
+    }
+
+    pub fn formatting(&self, params: DocumentFormattingParams) -> Option<Vec<TextEdit>> {
+        self.format_lines(&params.text_document.uri, &params.options, None)
+    }
+
+    pub fn range_formatting(&self, params: DocumentRangeFormattingParams) -> Option<Vec<TextEdit>> {
+        let range = params.range;
+        // Selections ending at the start of a line do not include that line
+        let end_line = match range.end.character {
+            0 if range.end.line > range.start.line => range.end.line,
+            _ => range.end.line + 1,
+        };
+        self.format_lines(
+            &params.text_document.uri,
+            &params.options,
+            Some(range.start.line as usize..end_line as usize),
+        )
+    }
+}
@@ -0,0 +1,210 @@
+use crate::builtin::{builtins_named, Builtin, BuiltinKind};
+use crate::tree_parser::DefinitionInfo;
+
+use super::*;
+
+/// A definition found in the include tree, and the files including it from the tree root
+struct IncludedDefinition {
+    file_path: Rc<PathBuf>,
+    include_chain: Vec<Rc<PathBuf>>,
+    info: DefinitionInfo,
+}
+
+/// Files from the tree root to the file at `index`, not including itself
+fn include_chain(tree_files: &[(Rc<PathBuf>, Rc<WorkspaceFile>)], mut index: usize) -> Vec<Rc<PathBuf>> {
+    let mut chain = vec![];
+    // Include tree is in depth-first order, so the first file including it is its parent in tree
+    while let Some(parent) = tree_files[..index].iter().position(|(_, workspace_file)| {
+        workspace_file
+            .including_files()
+            .borrow()
+            .iter()
+            .any(|(_, _, _, include_path, _)| *include_path == tree_files[index].0)
+    }) {
+        chain.push(tree_files[parent].0.clone());
+        index = parent;
+    }
+    chain.reverse();
+    chain
+}
+
+fn search_include_tree(name: &str, root_path: &Rc<PathBuf>, root_file: &Rc<WorkspaceFile>, definitions: &mut Vec<IncludedDefinition>) {
+    let tree_files = WorkspaceFile::include_tree(root_file, root_path);
+    for (index, (file_path, workspace_file)) in tree_files.iter().enumerate() {
+        let infos = TreeParser::named_definitions(
+            name,
+            &workspace_file.tree().borrow(),
+            &workspace_file.content().borrow(),
+            &workspace_file.line_mapping().borrow(),
+        );
+        for info in infos {
+            // The same file may be included by multiple shaders
+            if definitions
+                .iter()
+                .any(|definition| definition.file_path == *file_path && definition.info.range == info.range)
+            {
+                continue;
+            }
+            definitions.push(IncludedDefinition {
+                file_path: file_path.clone(),
+                include_chain: include_chain(&tree_files, index),
+                info,
+            });
+        }
+    }
+}
+
+fn definition_markdown(info: &DefinitionInfo) -> String {
+    let mut value = "```glsl\n".to_owned() + &info.signature + "\n```";
+    if let Some(documentation) = &info.documentation {
+        value += "\n\n";
+        value += documentation;
+    }
+    value
+}
+
+/// Whether this global definition declares a uniform or attribute provided by shader loaders
+fn declares_builtin(info: &DefinitionInfo, builtin: &Builtin) -> bool {
+    let mut qualifiers = info.signature.split_whitespace();
+    match builtin.kind {
+        BuiltinKind::Uniform => qualifiers.any(|word| word == "uniform"),
+        BuiltinKind::Attribute => qualifiers.any(|word| word == "in" || word == "attribute"),
+        _ => false,
+    }
+}
+
+/// Documentation of built-ins, warning those not available in the program, stage and `#version` of this shader
+fn builtin_markdown(builtins: &[Builtin], shader: Option<(&str, ShaderStage)>, version: Option<GlslVersion>) -> Option<String> {
+    if builtins.is_empty() {
+        return None;
+    }
+    let value = builtins
+        .iter()
+        .map(|builtin| match (shader, version) {
+            (_, Some(version)) if !builtin.in_version(version) => builtin.markdown() + &format!("\n\n**Not available in `{}`**", version),
+            (Some((program, _)), _) if !builtin.is_available(shader, None) => {
+                builtin.markdown() + &format!("\n\n**Not available in this shader of `{}`**", program)
+            }
+            _ => builtin.markdown(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n");
+    Some(value)
+}
+
+impl MinecraftLanguageServer {
+    pub fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(&params.text_document_position_params.text_document.uri);
+        let position = params.text_document_position_params.position;
+
+        let workspace_file = workspace_files.get_key_value(&file_path);
+        let file: &dyn ShaderFile = if let Some((_, workspace_file)) = workspace_file {
+            workspace_file as &WorkspaceFile
+        } else {
+            temp_files.get(&file_path)?
+        };
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+
+        if let Some(hover) = TreeParser::const_option_hover(position, &tree, &content, &line_mapping) {
+            return Some(hover);
+        }
+
+        let (name, range, local_definition) = TreeParser::identifier_definition(position, &tree, &content, &line_mapping)?;
+        let builtins = builtins_named(&name);
+        let builtin_documentation = |info: &DefinitionInfo| {
+            builtins
+                .iter()
+                .filter(|builtin| declares_builtin(info, builtin))
+                .map(|builtin| "\n\n".to_owned() + &builtin.documentation())
+                .collect::<String>()
+        };
+        let value = if let Some(info) = local_definition {
+            definition_markdown(&info)
+        } else if let Some((file_path, workspace_file)) = workspace_file {
+            let mut definitions = vec![];
+            search_include_tree(&name, file_path, workspace_file, &mut definitions);
+            // Include files may use definitions from other files included by their shaders
+            if definitions.is_empty() {
+                let mut parent_shaders = workspace_file
+                    .parent_shaders()
+                    .borrow()
+                    .iter()
+                    .map(|(shader_path, (shader_file, _))| (shader_path.clone(), shader_file.clone()))
+                    .collect::<Vec<_>>();
+                parent_shaders.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+                for (shader_path, shader_file) in &parent_shaders {
+                    search_include_tree(&name, shader_path, shader_file, &mut definitions);
+                }
+            }
+            if definitions.is_empty() {
+                let programs = server_data.programs.borrow();
+                let shader =
+                    Self::shader_program(&programs, workspace_file, file_path).map(|(program, stage)| (program.name.as_str(), stage));
+                builtin_markdown(builtins, shader, Self::glsl_version(workspace_file))?
+            } else {
+                let pack_path = &workspace_file.shader_pack().path;
+                let relative_path = |path: &Path| path.strip_prefix(pack_path).unwrap_or(path).to_str().unwrap().replace('\\', "/");
+                definitions
+                    .iter()
+                    .map(|definition| {
+                        let line = definition.info.range.start.line + 1;
+                        let mut value = definition_markdown(&definition.info) + &builtin_documentation(&definition.info);
+                        if definition.file_path == *file_path {
+                            value += &format!("\n\nDefined at line {}", line);
+                        } else {
+                            let url = path_to_url(&definition.file_path);
+                            value += &format!(
+                                "\n\nDefined in [`{}`]({}#L{}) line {}",
+                                relative_path(&definition.file_path),
+                                url,
+                                line,
+                                line
+                            );
+                        }
+                        if !definition.include_chain.is_empty() && definition.include_chain != [file_path.clone()] {
+                            let include_chain = definition
+                                .include_chain
+                                .iter()
+                                .map(|path| format!("`{}`", relative_path(path)))
+                                .collect::<Vec<_>>();
+                            value += ", included through ";
+                            value += &include_chain.join(" → ");
+                        }
+                        value
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n---\n\n")
+            }
+        } else {
+            let definitions = TreeParser::named_definitions(&name, &tree, &content, &line_mapping);
+            if definitions.is_empty() {
+                builtin_markdown(builtins, None, GlslVersion::parse(&content))?
+            } else {
+                definitions
+                    .iter()
+                    .map(|info| {
+                        definition_markdown(info)
+                            + &builtin_documentation(info)
+                            + &format!("\n\nDefined at line {}", info.range.start.line + 1)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n---\n\n")
+            }
+        };
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(range),
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use crate::tree_parser::HintContext;
+
+use super::*;
+
+impl MinecraftLanguageServer {
+    pub fn inlay_hint(&self, params: InlayHintParams) -> Option<Vec<InlayHint>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(&params.text_document.uri);
+
+        // Macros provided by OptiFine are defined before any code of shaders
+        let mut context = HintContext::new(OPTIFINE_MACROS);
+        let file: &dyn ShaderFile = if let Some((file_path, workspace_file)) = workspace_files.get_key_value(&file_path) {
+            for (_, tree_file) in Self::visible_files(file_path, workspace_file) {
+                context.extend(&tree_file.tree().borrow(), &tree_file.content().borrow());
+            }
+            workspace_file as &WorkspaceFile
+        } else {
+            let temp_file = temp_files.get(&file_path)?;
+            context.extend(&temp_file.tree().borrow(), &temp_file.content().borrow());
+            temp_file
+        };
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+
+        let rows = params.range.start.line as usize..params.range.end.line as usize + 1;
+        Some(TreeParser::inlay_hints(&tree, &content, &line_mapping, &context, rows))
+    }
+}
@@ -6,7 +6,7 @@ impl MinecraftLanguageServer {
         let workspace_files = server_data.workspace_files.borrow();
         let temp_files = server_data.temp_files.borrow();
 
-        let file_path = params.text_document.uri.to_file_path().unwrap();
+        let file_path = url_to_path(&params.text_document.uri);
 
         let file: &dyn ShaderFile = if let Some(workspace_file) = workspace_files.get(&file_path) {
             workspace_file as &WorkspaceFile
@@ -1,8 +1,8 @@
 use std::cell::RefCell;
-use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::str::FromStr;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 use logging::{error, info, warn};
 
@@ -13,26 +13,42 @@ use tower_lsp::lsp_types::{request::*, *};
 use tower_lsp::{Client, LanguageServer};
 use tree_sitter::Parser;
 
+mod call_hierarchy;
 mod change_file;
 mod close_file;
+mod code_action;
+mod code_lens;
+mod completion;
+mod document_highlight;
 mod document_links;
 mod error;
 mod find_definitions;
 mod find_references;
+mod folding_range;
+mod formatting;
+mod hover;
+mod inlay_hint;
 mod list_symbols;
 mod open_file;
+mod rename;
 mod rename_files;
 mod save_file;
+mod selection_range;
+mod semantic_tokens;
+mod signature_help;
 mod update_watched_files;
 mod update_workspaces;
 mod utility;
+mod workspace_symbol;
 
+use crate::builtin::GlslVersion;
 use crate::capability::ServerCapabilitiesFactroy;
 use crate::configuration::Configuration;
 use crate::constant::*;
 use crate::file::*;
 use crate::notification;
-use crate::tree_parser::TreeParser;
+use crate::pipeline::{collect_programs, program_of_file, FallbackTree, Loader, ProgramInfo, ProgramRegistry, RenderPipeline, ShaderStage};
+use crate::tree_parser::{FormatStyle, TreeParser};
 
 pub type Diagnostics = HashMap<Url, Vec<Diagnostic>>;
 
@@ -40,10 +56,14 @@ pub type Diagnostics = HashMap<Url, Vec<Diagnostic>>;
 /// By sending the Mutex of server data to snyc functions, we can handle it like single thread
 pub struct ServerData {
     temp_lint: RefCell<bool>,
+    format_style: RefCell<FormatStyle>,
     extensions: RefCell<HashSet<String>>,
+    programs: RefCell<ProgramRegistry>,
     shader_packs: RefCell<HashSet<Rc<ShaderPack>>>,
     workspace_files: RefCell<HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>>,
     temp_files: RefCell<HashMap<PathBuf, TempFile>>,
+    /// Last semantic tokens sent for each file with their result ids, for delta requests
+    semantic_tokens: RefCell<HashMap<PathBuf, (u32, Vec<SemanticToken>)>>,
     tree_sitter_parser: RefCell<Parser>,
 }
 
@@ -53,10 +73,13 @@ impl ServerData {
         tree_sitter_parser.set_language(tree_sitter_glsl::language()).unwrap();
         ServerData {
             temp_lint: RefCell::new(false),
+            format_style: RefCell::new(FormatStyle::default()),
             extensions: RefCell::new(BASIC_EXTENSIONS.clone()),
+            programs: RefCell::new(ProgramRegistry::new(&[])),
             shader_packs: RefCell::new(HashSet::new()),
             workspace_files: RefCell::new(HashMap::new()),
             temp_files: RefCell::new(HashMap::new()),
+            semantic_tokens: RefCell::new(HashMap::new()),
             tree_sitter_parser: RefCell::new(tree_sitter_parser),
         }
     }
@@ -68,6 +91,14 @@ impl ServerData {
     pub fn temp_files(&self) -> &RefCell<HashMap<PathBuf, TempFile>> {
         &self.temp_files
     }
+
+    pub fn shader_packs(&self) -> &RefCell<HashSet<Rc<ShaderPack>>> {
+        &self.shader_packs
+    }
+
+    pub fn programs(&self) -> &RefCell<ProgramRegistry> {
+        &self.programs
+    }
 }
 
 // We will not send cloned Rc data to solution outside the mutex lock
@@ -144,6 +175,21 @@ impl LanguageServer for MinecraftLanguageServer {
 
     #[logging::with_trace_id]
     async fn initialized(&self, _params: InitializedParams) {
+        let diagnostics = {
+            let server_data = self.server_data.lock().unwrap();
+            let shader_packs = server_data.shader_packs.borrow();
+            let workspace_files = server_data.workspace_files.borrow();
+            let programs = server_data.programs.borrow();
+
+            let mut update_list = HashMap::new();
+            shader_packs.iter().for_each(|shader_pack| {
+                self.update_render_targets(shader_pack, &programs, &workspace_files, &mut update_list);
+            });
+            let mut diagnostics = self.collect_diagnostics(&update_list);
+            diagnostics.extend(self.collect_properties_diagnostics(shader_packs.iter()));
+            diagnostics
+        };
+        self.publish_diagnostic(diagnostics).await;
         self.set_status_ready().await;
     }
 
@@ -178,9 +224,14 @@ impl LanguageServer for MinecraftLanguageServer {
 
         config.extra_extension.extend(BASIC_EXTENSIONS.clone());
 
-        let server_data = self.server_data.lock().unwrap();
-        *server_data.extensions.borrow_mut() = config.extra_extension;
-        *server_data.temp_lint.borrow_mut() = config.temp_lint;
+        let diagnostics = {
+            let server_data = self.server_data.lock().unwrap();
+            *server_data.extensions.borrow_mut() = config.extra_extension;
+            *server_data.temp_lint.borrow_mut() = config.temp_lint;
+            *server_data.format_style.borrow_mut() = config.format;
+            self.update_programs(&server_data, ProgramRegistry::new(&config.extra_programs))
+        };
+        self.publish_diagnostic(diagnostics).await;
     }
 
     #[logging::with_trace_id]
@@ -232,16 +283,111 @@ impl LanguageServer for MinecraftLanguageServer {
         Ok(self.find_definitions(params).map(GotoDefinitionResponse::Array))
     }
 
+    #[logging::with_trace_id]
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        Ok(self.code_lens(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        Ok(self.hover(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        Ok(self.completion(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        Ok(self.signature_help(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        Ok(self.code_action(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        Ok(self.inlay_hint(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        Ok(self.folding_range(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        Ok(self.formatting(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn range_formatting(&self, params: DocumentRangeFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        Ok(self.range_formatting(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn semantic_tokens_full(&self, params: SemanticTokensParams) -> Result<Option<SemanticTokensResult>> {
+        Ok(self.semantic_tokens_full(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn semantic_tokens_full_delta(&self, params: SemanticTokensDeltaParams) -> Result<Option<SemanticTokensFullDeltaResult>> {
+        Ok(self.semantic_tokens_full_delta(params))
+    }
+
     #[logging::with_trace_id]
     async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
         Ok(self.find_references(params))
     }
 
+    #[logging::with_trace_id]
+    async fn prepare_rename(&self, params: TextDocumentPositionParams) -> Result<Option<PrepareRenameResponse>> {
+        self.prepare_rename(params)
+    }
+
+    #[logging::with_trace_id]
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        self.rename(params)
+    }
+
+    #[logging::with_trace_id]
+    async fn prepare_call_hierarchy(&self, params: CallHierarchyPrepareParams) -> Result<Option<Vec<CallHierarchyItem>>> {
+        Ok(self.prepare_call_hierarchy(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn incoming_calls(&self, params: CallHierarchyIncomingCallsParams) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        Ok(self.incoming_calls(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn outgoing_calls(&self, params: CallHierarchyOutgoingCallsParams) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        Ok(self.outgoing_calls(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn document_highlight(&self, params: DocumentHighlightParams) -> Result<Option<Vec<DocumentHighlight>>> {
+        Ok(self.document_highlight(params))
+    }
+
+    #[logging::with_trace_id]
+    async fn selection_range(&self, params: SelectionRangeParams) -> Result<Option<Vec<SelectionRange>>> {
+        Ok(self.selection_range(params))
+    }
+
     #[logging::with_trace_id]
     async fn document_symbol(&self, params: DocumentSymbolParams) -> Result<Option<DocumentSymbolResponse>> {
         Ok(self.list_symbols(params))
     }
 
+    #[logging::with_trace_id]
+    async fn symbol(&self, params: WorkspaceSymbolParams) -> Result<Option<Vec<SymbolInformation>>> {
+        Ok(self.workspace_symbol(params))
+    }
+
     #[logging::with_trace_id]
     async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
         self.set_status_loading("Applying work space changes...".to_owned()).await;
@@ -2,12 +2,13 @@ use super::*;
 
 impl MinecraftLanguageServer {
     pub fn open_file(&self, params: DidOpenTextDocumentParams) {
-        let file_path = params.text_document.uri.to_file_path().unwrap();
+        let file_path = url_to_path(&params.text_document.uri);
 
         let server_data = self.server_data.lock().unwrap();
         let mut parser = server_data.tree_sitter_parser.borrow_mut();
         let mut workspace_files = server_data.workspace_files.borrow_mut();
         let mut temp_files = server_data.temp_files.borrow_mut();
+        let programs = server_data.programs.borrow();
 
         if let Some((file_path, workspace_file)) = workspace_files.get_key_value(&file_path) {
             let content = params.text_document.text;
@@ -30,7 +31,7 @@ impl MinecraftLanguageServer {
                 1,
             );
         } else {
-            let temp_file = TempFile::new(&mut parser, &file_path, params.text_document.text);
+            let temp_file = TempFile::new(&mut parser, &programs, &file_path, params.text_document.text);
             temp_files.insert(file_path, temp_file);
         }
     }
@@ -0,0 +1,155 @@
+use crate::builtin::{builtins_named, KEYWORDS};
+use crate::tree_parser::Symbol;
+
+use super::find_references::FileReferences;
+use super::*;
+
+/// Whether this name can be declared in GLSL, names starting with `gl_` or containing `__` are reserved
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|char: char| char.is_ascii_alphabetic() || char == '_')
+        && name.chars().all(|char| char.is_ascii_alphanumeric() || char == '_')
+        && !name.starts_with("gl_")
+        && !name.contains("__")
+        && !KEYWORDS.contains(&name)
+}
+
+impl MinecraftLanguageServer {
+    /// The symbol at this position and its references, if it is defined in writable files of this workspace
+    fn rename_target(
+        file_path: &PathBuf, position: Position, workspace_files: &HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>,
+        temp_files: &HashMap<PathBuf, TempFile>,
+    ) -> Result<(Symbol, FileReferences, Range)> {
+        let (symbol, references) = Self::symbol_references(file_path, position, workspace_files, temp_files)
+            .ok_or_else(|| LanguageServerError::invalid_rename_error("No symbol to rename here".to_owned()))?;
+
+        if !references
+            .iter()
+            .any(|(_, references)| references.iter().any(|reference| reference.is_definition))
+        {
+            return Err(LanguageServerError::invalid_rename_error(format!(
+                "`{}` is not defined in this shader pack",
+                symbol.name()
+            )));
+        }
+        if references.iter().any(|(url, _)| {
+            workspace_files
+                .get(&url_to_path(url))
+                .is_some_and(|workspace_file| workspace_file.shader_pack().archive.is_some())
+        }) {
+            return Err(LanguageServerError::invalid_rename_error(
+                "Files in zipped shader packs are read-only".to_owned(),
+            ));
+        }
+
+        let url = path_to_url(file_path);
+        let range = references
+            .iter()
+            .filter(|(reference_url, _)| *reference_url == url)
+            .flat_map(|(_, references)| references)
+            .map(|reference| reference.range)
+            .find(|range| range.start <= position && position <= range.end)
+            .ok_or_else(|| LanguageServerError::invalid_rename_error("No symbol to rename here".to_owned()))?;
+        Ok((symbol, references, range))
+    }
+
+    pub fn prepare_rename(&self, params: TextDocumentPositionParams) -> Result<Option<PrepareRenameResponse>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(&params.text_document.uri);
+        let (symbol, _, range) = Self::rename_target(&file_path, params.position, &workspace_files, &temp_files)?;
+        Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+            range,
+            placeholder: symbol.name().to_owned(),
+        }))
+    }
+
+    pub fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(&params.text_document_position.text_document.uri);
+        let new_name = params.new_name;
+        let (symbol, references, _) =
+            Self::rename_target(&file_path, params.text_document_position.position, &workspace_files, &temp_files)?;
+
+        if new_name == symbol.name() {
+            return Ok(None);
+        }
+        if !is_identifier(&new_name) {
+            return Err(LanguageServerError::invalid_rename_error(format!(
+                "`{}` is not a valid identifier",
+                new_name
+            )));
+        }
+        if !builtins_named(&new_name).is_empty() {
+            return Err(LanguageServerError::invalid_rename_error(format!(
+                "`{}` is a GLSL built-in",
+                new_name
+            )));
+        }
+
+        // Globals with the new name anywhere the symbol is visible
+        let mut visible_files: Vec<(Rc<PathBuf>, Rc<WorkspaceFile>)> = vec![];
+        if !matches!(symbol, Symbol::Field { .. }) {
+            for (url, _) in &references {
+                if let Some((reference_path, workspace_file)) = workspace_files.get_key_value(&url_to_path(url)) {
+                    for (tree_path, tree_file) in Self::visible_files(reference_path, workspace_file) {
+                        if !visible_files.iter().any(|(path, _)| *path == tree_path) {
+                            visible_files.push((tree_path, tree_file));
+                        }
+                    }
+                }
+            }
+        }
+        let global_collision = visible_files
+            .iter()
+            .any(|(_, tree_file)| TreeParser::defines(&new_name, &tree_file.tree().borrow(), &tree_file.content().borrow()));
+        let collision = global_collision
+            || references.iter().any(|(url, references)| {
+                let reference_path = url_to_path(url);
+                let file: &dyn ShaderFile = match workspace_files.get(&reference_path) {
+                    Some(workspace_file) => workspace_file as &WorkspaceFile,
+                    None => match temp_files.get(&reference_path) {
+                        Some(temp_file) => temp_file,
+                        None => return false,
+                    },
+                };
+                TreeParser::rename_collides(
+                    &symbol,
+                    &new_name,
+                    references,
+                    &file.tree().borrow(),
+                    &file.content().borrow(),
+                    &file.line_mapping().borrow(),
+                )
+            });
+        if collision {
+            return Err(LanguageServerError::invalid_rename_error(format!(
+                "`{}` is already defined",
+                new_name
+            )));
+        }
+
+        let changes = references
+            .into_iter()
+            .map(|(url, references)| {
+                let edits = references
+                    .into_iter()
+                    .map(|reference| TextEdit {
+                        range: reference.range,
+                        new_text: new_name.clone(),
+                    })
+                    .collect();
+                (url, edits)
+            })
+            .collect();
+        Ok(Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }))
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 
-fn abstract_include_path(pack_path: &Path, absolute_path: &Path) -> core::result::Result<String, ()> {
+pub(super) fn abstract_include_path(pack_path: &Path, absolute_path: &Path) -> core::result::Result<String, ()> {
     let mut pack_path_components = pack_path.components();
     let mut absolute_path_components = absolute_path.components();
 
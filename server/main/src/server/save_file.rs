@@ -2,7 +2,7 @@ use super::*;
 
 impl MinecraftLanguageServer {
     pub fn save_file(&self, url: Url) -> Option<Diagnostics> {
-        let file_path = url.to_file_path().unwrap();
+        let file_path = url_to_path(&url);
 
         let server_data = self.server_data.lock().unwrap();
         let mut parser = server_data.tree_sitter_parser.borrow_mut();
@@ -13,7 +13,7 @@ impl MinecraftLanguageServer {
 
         let diagnostics = if let Some((file_path, workspace_file)) = workspace_files.get_key_value(&file_path) {
             // If this file is ended with watched extension, it should get updated through update_watched_files
-            if file_path.extension().map_or(true, |ext| extensions.contains(ext.to_str().unwrap())) {
+            if file_path.extension().is_none_or(|ext| extensions.contains(ext.to_str().unwrap())) {
                 return None;
             }
             workspace_file.update_from_disc(&mut parser, file_path);
@@ -0,0 +1,93 @@
+use crate::tree_parser::TokenKinds;
+
+use super::*;
+
+/// A single edit turning the previous tokens into the current ones, offsets count integers of encoded tokens
+fn tokens_edit(previous: &[SemanticToken], current: &[SemanticToken]) -> SemanticTokensEdit {
+    let prefix = previous.iter().zip(current).take_while(|(a, b)| a == b).count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    SemanticTokensEdit {
+        start: prefix as u32 * 5,
+        delete_count: (previous.len() - prefix - suffix) as u32 * 5,
+        data: Some(current[prefix..current.len() - suffix].to_vec()),
+    }
+}
+
+impl MinecraftLanguageServer {
+    /// Semantic tokens of this file, names are classified by definitions across its include tree
+    fn file_semantic_tokens(server_data: &MutexGuard<ServerData>, file_path: &PathBuf) -> Option<Vec<SemanticToken>> {
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let mut globals = TokenKinds::default();
+        let file: &dyn ShaderFile = if let Some((file_path, workspace_file)) = workspace_files.get_key_value(file_path) {
+            for (_, tree_file) in Self::visible_files(file_path, workspace_file) {
+                globals.extend(&tree_file.tree().borrow(), &tree_file.content().borrow());
+            }
+            workspace_file as &WorkspaceFile
+        } else {
+            let temp_file = temp_files.get(file_path)?;
+            globals.extend(&temp_file.tree().borrow(), &temp_file.content().borrow());
+            temp_file
+        };
+        // Definitions in this file take precedence
+        globals.extend(&file.tree().borrow(), &file.content().borrow());
+
+        let tokens = TreeParser::semantic_tokens(
+            &file.tree().borrow(),
+            &file.content().borrow(),
+            &file.line_mapping().borrow(),
+            &globals,
+        );
+        Some(tokens)
+    }
+
+    /// Remember tokens of this file for later delta requests and return their result id
+    fn cache_semantic_tokens(server_data: &MutexGuard<ServerData>, file_path: PathBuf, tokens: Vec<SemanticToken>) -> String {
+        let mut semantic_tokens = server_data.semantic_tokens.borrow_mut();
+        let result_id = semantic_tokens.get(&file_path).map_or(0, |(result_id, _)| result_id + 1);
+        semantic_tokens.insert(file_path, (result_id, tokens));
+        result_id.to_string()
+    }
+
+    pub fn semantic_tokens_full(&self, params: SemanticTokensParams) -> Option<SemanticTokensResult> {
+        let server_data = self.server_data.lock().unwrap();
+        let file_path = url_to_path(&params.text_document.uri);
+
+        let tokens = Self::file_semantic_tokens(&server_data, &file_path)?;
+        let result_id = Self::cache_semantic_tokens(&server_data, file_path, tokens.clone());
+        Some(SemanticTokensResult::Tokens(SemanticTokens {
+            result_id: Some(result_id),
+            data: tokens,
+        }))
+    }
+
+    pub fn semantic_tokens_full_delta(&self, params: SemanticTokensDeltaParams) -> Option<SemanticTokensFullDeltaResult> {
+        let server_data = self.server_data.lock().unwrap();
+        let file_path = url_to_path(&params.text_document.uri);
+
+        let tokens = Self::file_semantic_tokens(&server_data, &file_path)?;
+        let edit = server_data
+            .semantic_tokens
+            .borrow()
+            .get(&file_path)
+            .filter(|(result_id, _)| result_id.to_string() == params.previous_result_id)
+            .map(|(_, previous)| tokens_edit(previous, &tokens));
+        let result_id = Self::cache_semantic_tokens(&server_data, file_path, tokens.clone());
+        match edit {
+            Some(edit) => Some(SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+                result_id: Some(result_id),
+                edits: vec![edit],
+            })),
+            None => Some(SemanticTokensFullDeltaResult::Tokens(SemanticTokens {
+                result_id: Some(result_id),
+                data: tokens,
+            })),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::builtin::{builtins_named, Builtin, BuiltinKind};
+use crate::tree_parser::signature_information;
+
+use super::*;
+
+impl MinecraftLanguageServer {
+    pub fn signature_help(&self, params: SignatureHelpParams) -> Option<SignatureHelp> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+        let temp_files = server_data.temp_files.borrow();
+
+        let file_path = url_to_path(&params.text_document_position_params.text_document.uri);
+        let position = params.text_document_position_params.position;
+
+        let workspace_file = workspace_files.get_key_value(&file_path);
+        let file: &dyn ShaderFile = if let Some((_, workspace_file)) = workspace_file {
+            workspace_file as &WorkspaceFile
+        } else {
+            temp_files.get(&file_path)?
+        };
+
+        let content = file.content().borrow();
+        let tree = file.tree().borrow();
+        let line_mapping = file.line_mapping().borrow();
+
+        let programs = server_data.programs.borrow();
+        let (shader, version) = match workspace_file {
+            Some((file_path, workspace_file)) => (
+                Self::shader_program(&programs, workspace_file, file_path).map(|(program, stage)| (program.name.as_str(), stage)),
+                Self::glsl_version(workspace_file),
+            ),
+            None => (None, GlslVersion::parse(&content)),
+        };
+
+        let (name, active_parameter) = TreeParser::current_call(position, &tree, &content, &line_mapping)?;
+        let mut signatures = match workspace_file {
+            Some((file_path, workspace_file)) => Self::visible_files(file_path, workspace_file)
+                .iter()
+                .flat_map(|(_, tree_file)| {
+                    TreeParser::function_signatures(&name, &tree_file.tree().borrow(), &tree_file.content().borrow())
+                })
+                .collect::<Vec<_>>(),
+            None => TreeParser::function_signatures(&name, &tree, &content),
+        };
+        signatures.extend(
+            builtins_named(&name)
+                .iter()
+                .filter(|builtin| builtin.kind == BuiltinKind::Function && builtin.is_available(shader, version))
+                .flat_map(|builtin| {
+                    builtin.signatures.iter().map(|signature| {
+                        signature_information(
+                            signature.to_string(),
+                            &Builtin::parameter_offsets(signature),
+                            Some(builtin.description.to_owned()),
+                        )
+                    })
+                }),
+        );
+        // Functions may be defined the same in different preprocessor branches
+        let mut labels = HashSet::new();
+        signatures.retain(|signature| labels.insert(signature.label.clone()));
+        if signatures.is_empty() {
+            return None;
+        }
+
+        // The first overload with enough parameters
+        let active_signature = signatures
+            .iter()
+            .position(|signature| {
+                signature
+                    .parameters
+                    .as_ref()
+                    .is_some_and(|parameters| parameters.len() > active_parameter as usize)
+            })
+            .unwrap_or(0);
+        Some(SignatureHelp {
+            signatures,
+            active_signature: Some(active_signature as u32),
+            active_parameter: Some(active_parameter),
+        })
+    }
+}
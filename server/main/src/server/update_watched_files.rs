@@ -6,12 +6,17 @@ impl MinecraftLanguageServer {
         let mut parser = server_data.tree_sitter_parser.borrow_mut();
         let mut workspace_files = server_data.workspace_files.borrow_mut();
         let mut temp_files = server_data.temp_files.borrow_mut();
-        let shader_packs = server_data.shader_packs.borrow();
+        let mut shader_packs = server_data.shader_packs.borrow_mut();
         let extensions = server_data.extensions.borrow();
+        let programs = server_data.programs.borrow();
 
         let mut updated_shaders = HashMap::new();
         let mut update_list = HashMap::new();
         let mut change_list = HashMap::new();
+        let mut updated_packs = vec![];
+        let mut dimension_packs = vec![];
+        let mut archive_packs = vec![];
+        let mut cleared_files = vec![];
 
         for change in changes {
             let file_path = change.uri.to_file_path().unwrap();
@@ -23,11 +28,50 @@ impl MinecraftLanguageServer {
         }
 
         for (file_path, change_type) in change_list {
+            if file_path.extension().is_some_and(|ext| ext == "zip") {
+                // Zipped shader packs are reloaded as a whole
+                let root_path = archive_root(&file_path);
+                let removed_packs: HashSet<_> = shader_packs
+                    .drain_filter(|shader_pack| shader_pack.path.starts_with(&root_path))
+                    .collect();
+                cleared_files.extend(removed_packs.iter().map(|shader_pack| shader_pack.properties_path()));
+                cleared_files.extend(
+                    workspace_files
+                        .drain_filter(|_, workspace_file| removed_packs.contains(workspace_file.shader_pack()))
+                        .map(|(file_path, _)| file_path.to_path_buf()),
+                );
+                // Known archives are reloaded wherever they are, new ones are only found in `shaderpacks` folders
+                let is_archive_folder = file_path
+                    .parent()
+                    .is_some_and(|folder| Self::is_archive_folder(folder, !removed_packs.is_empty()));
+                if change_type != FileChangeType::DELETED && is_archive_folder {
+                    if let Some(shader_pack) = Self::load_shader_archive(&extensions, &file_path) {
+                        let shader_pack = Rc::new(shader_pack);
+                        Self::scan_shader_pack(&mut parser, &programs, &mut workspace_files, &mut temp_files, &shader_pack);
+                        shader_packs.insert(shader_pack.clone());
+                        archive_packs.push(shader_pack);
+                    }
+                }
+                continue;
+            }
+            if let Some(shader_pack) = shader_packs.iter().find(|shader_pack| shader_pack.is_properties_file(&file_path)) {
+                shader_pack.update_properties();
+                updated_packs.push(shader_pack.clone());
+                continue;
+            }
+            if let Some(shader_pack) = shader_packs
+                .iter()
+                .find(|shader_pack| shader_pack.is_dimension_properties_file(&file_path))
+            {
+                // Newly declared dimension folders may contain programs
+                shader_pack.update_dimensions();
+                Self::scan_shader_pack(&mut parser, &programs, &mut workspace_files, &mut temp_files, shader_pack);
+                dimension_packs.push(shader_pack.clone());
+                continue;
+            }
             if change_type == FileChangeType::DELETED {
                 // If a path is not watched through extension, it might be a folder
-                let is_watched_file = file_path
-                    .extension()
-                    .map_or(false, |ext| extensions.contains(ext.to_str().unwrap()));
+                let is_watched_file = file_path.extension().is_some_and(|ext| extensions.contains(ext.to_str().unwrap()));
                 // Folder handling is much more expensive than file handling
                 // Almost nobody will name a folder with watched extension, right?
                 if is_watched_file {
@@ -62,17 +106,7 @@ impl MinecraftLanguageServer {
                         });
                 }
             } else {
-                let is_valid_shader = self.is_valid_shader(&shader_packs, &file_path).map(|pack_path| {
-                    let shader_type = match file_path.extension() {
-                        Some(ext) if ext == "vsh" => gl::VERTEX_SHADER,
-                        Some(ext) if ext == "gsh" => gl::GEOMETRY_SHADER,
-                        Some(ext) if ext == "fsh" => gl::FRAGMENT_SHADER,
-                        Some(ext) if ext == "csh" => gl::COMPUTE_SHADER,
-                        // This will never be used since we have ensured the extension through basic shaders regex.
-                        _ => gl::NONE,
-                    };
-                    (pack_path, shader_type)
-                });
+                let is_valid_shader = self.is_valid_shader(&shader_packs, &programs, &file_path);
                 let (file_path, workspace_file) = match workspace_files.get_key_value(&file_path) {
                     Some((file_path, changed_file)) => {
                         let mut file_type = changed_file.file_type().borrow_mut();
@@ -133,7 +167,31 @@ impl MinecraftLanguageServer {
         for (file_path, shader_file) in &updated_shaders {
             self.lint_workspace_shader(shader_file, file_path, &mut update_list);
         }
-        let diagnostics = self.collect_diagnostics(&update_list);
+        // Any change in shader files may change buffer usages of programs
+        let mut pipeline_packs = update_list
+            .values()
+            .map(|workspace_file| workspace_file.shader_pack().clone())
+            .collect::<Vec<_>>();
+        pipeline_packs.extend(updated_packs.iter().cloned());
+        pipeline_packs.extend(dimension_packs);
+        pipeline_packs.extend(archive_packs.iter().cloned());
+        pipeline_packs.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        pipeline_packs.dedup();
+        for shader_pack in &pipeline_packs {
+            self.update_render_targets(shader_pack, &programs, &workspace_files, &mut update_list);
+        }
+        // Custom uniforms changed, uniform declarations in all files of these packs need to be checked again.
+        update_list.extend(
+            workspace_files
+                .iter()
+                .filter(|(_, workspace_file)| {
+                    *workspace_file.file_type().borrow() != gl::INVALID_ENUM && updated_packs.contains(workspace_file.shader_pack())
+                })
+                .map(|(file_path, workspace_file)| (file_path.clone(), workspace_file.clone())),
+        );
+        let mut diagnostics = self.collect_diagnostics(&update_list);
+        diagnostics.extend(cleared_files.iter().map(|file_path| (path_to_url(file_path), vec![])));
+        diagnostics.extend(self.collect_properties_diagnostics(updated_packs.iter().chain(&archive_packs)));
 
         self.collect_memory(&mut workspace_files);
         diagnostics
@@ -7,6 +7,8 @@ impl MinecraftLanguageServer {
         let mut shader_packs = server_data.shader_packs.borrow_mut();
         let mut workspace_files = server_data.workspace_files.borrow_mut();
         let mut temp_files = server_data.temp_files.borrow_mut();
+        let programs = server_data.programs.borrow();
+        let extensions = server_data.extensions.borrow();
 
         let mut diagnostics: Diagnostics = HashMap::new();
         for removed_workspace in &events.removed {
@@ -14,16 +16,39 @@ impl MinecraftLanguageServer {
             let removed_shader_packs: HashSet<_> = shader_packs
                 .drain_filter(|pack_path| pack_path.path.starts_with(&removed_path))
                 .collect();
+            diagnostics.extend(
+                removed_shader_packs
+                    .iter()
+                    .map(|shader_pack| (path_to_url(&shader_pack.properties_path()), vec![])),
+            );
             diagnostics.extend(
                 workspace_files
                     .drain_filter(|_, workspace_file| removed_shader_packs.contains(workspace_file.shader_pack()))
-                    .map(|(file_path, _)| (Url::from_file_path(&file_path as &Path).unwrap(), vec![])),
+                    .map(|(file_path, _)| (path_to_url(&file_path), vec![])),
             );
         }
 
         for added_workspace in events.added {
             let added_path = added_workspace.uri.to_file_path().unwrap();
-            self.scan_files_in_root(&mut parser, &mut shader_packs, &mut workspace_files, &mut temp_files, added_path);
+            self.scan_files_in_root(
+                &mut parser,
+                &programs,
+                &extensions,
+                &mut shader_packs,
+                &mut workspace_files,
+                &mut temp_files,
+                added_path.clone(),
+            );
+            let added_packs = shader_packs
+                .iter()
+                .filter(|shader_pack| shader_pack.path.starts_with(&added_path))
+                .collect::<Vec<_>>();
+            let mut update_list = HashMap::new();
+            for shader_pack in &added_packs {
+                self.update_render_targets(shader_pack, &programs, &workspace_files, &mut update_list);
+            }
+            diagnostics.extend(self.collect_diagnostics(&update_list));
+            diagnostics.extend(self.collect_properties_diagnostics(added_packs.into_iter()));
         }
         diagnostics
     }
@@ -4,83 +4,171 @@ impl MinecraftLanguageServer {
     pub(super) fn collect_memory(&self, workspace_files: &mut HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>) {
         workspace_files.retain(|_file_path, workspace_file| {
             // Only delete file that both do not exist and no file includes it.
-            *workspace_file.file_type().borrow() != gl::INVALID_ENUM || workspace_file.included_files().borrow().len() > 0
+            *workspace_file.file_type().borrow() != gl::INVALID_ENUM || !workspace_file.included_files().borrow().is_empty()
         });
     }
 
-    pub(super) fn is_valid_shader<'a>(&'a self, shader_packs: &'a HashSet<Rc<ShaderPack>>, file_path: &Path) -> Option<&'a Rc<ShaderPack>> {
+    /// Returns the shader pack and the shader type if this file is a program of some shader pack
+    pub(super) fn is_valid_shader<'a>(
+        &'a self, shader_packs: &'a HashSet<Rc<ShaderPack>>, programs: &ProgramRegistry, file_path: &Path,
+    ) -> Option<(&'a Rc<ShaderPack>, u32)> {
         for shader_pack in shader_packs {
             if let Ok(relative_path) = file_path.strip_prefix(&shader_pack.path) {
-                let relative_path = relative_path.to_str().unwrap();
-                if RE_BASIC_SHADERS.is_match(relative_path) {
-                    return Some(shader_pack);
-                } else if let Some(result) = relative_path.split_once(MAIN_SEPARATOR) {
-                    if RE_DIMENSION_FOLDER.is_match(result.0) && RE_BASIC_SHADERS.is_match(result.1) {
-                        return Some(shader_pack);
-                    }
-                }
-                return None;
+                return programs
+                    .match_path(shader_pack, relative_path.to_str().unwrap())
+                    .map(|(_, stage)| (shader_pack, stage.gl_type()));
             }
         }
         None
     }
 
-    pub(super) fn find_shader_packs(shader_packs: &mut Vec<Rc<ShaderPack>>, curr_path: PathBuf) {
+    /// Returns the program and the shader stage if this workspace file is a shader
+    pub(super) fn shader_program<'a>(
+        programs: &'a ProgramRegistry, workspace_file: &WorkspaceFile, file_path: &Path,
+    ) -> Option<(&'a ProgramInfo, ShaderStage)> {
+        let shader_pack = workspace_file.shader_pack();
+        let relative_path = file_path.strip_prefix(&shader_pack.path).ok()?;
+        programs.match_path(shader_pack, relative_path.to_str()?)
+    }
+
+    /// `#version` of this file, or of the first shader including it for include files
+    pub(super) fn glsl_version(workspace_file: &WorkspaceFile) -> Option<GlslVersion> {
+        GlslVersion::parse(&workspace_file.content().borrow()).or_else(|| {
+            let parent_shaders = workspace_file.parent_shaders().borrow();
+            let mut parent_shaders = parent_shaders.iter().collect::<Vec<_>>();
+            parent_shaders.sort_unstable_by(|a, b| a.0.cmp(b.0));
+            parent_shaders
+                .into_iter()
+                .find_map(|(_, (shader_file, _))| GlslVersion::parse(&shader_file.content().borrow()))
+        })
+    }
+
+    /// Files whose definitions are visible from this file: its include tree, and for include files the include trees of their shaders
+    pub(super) fn visible_files(file_path: &Rc<PathBuf>, workspace_file: &Rc<WorkspaceFile>) -> Vec<(Rc<PathBuf>, Rc<WorkspaceFile>)> {
+        let mut files = WorkspaceFile::include_tree(workspace_file, file_path);
+        let mut parent_shaders = workspace_file
+            .parent_shaders()
+            .borrow()
+            .iter()
+            .map(|(shader_path, (shader_file, _))| (shader_path.clone(), shader_file.clone()))
+            .collect::<Vec<_>>();
+        parent_shaders.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        for (shader_path, shader_file) in &parent_shaders {
+            for (tree_path, tree_file) in WorkspaceFile::include_tree(shader_file, shader_path) {
+                if !files.iter().any(|(path, _)| *path == tree_path) {
+                    files.push((tree_path, tree_file));
+                }
+            }
+        }
+        files
+    }
+
+    /// Whether a zip file in this folder is loaded as a shader pack, only top-level zips of roots and zips in `shaderpacks` folders are
+    pub(super) fn is_archive_folder(folder: &Path, is_root: bool) -> bool {
+        is_root || folder.file_name().is_some_and(|name| name == "shaderpacks")
+    }
+
+    /// Zipped shader packs are read-only, their content is loaded into memory
+    pub(super) fn load_shader_archive(extensions: &HashSet<String>, archive_path: &Path) -> Option<ShaderPack> {
+        let shader_pack = ShaderPack::from_archive(archive_path, extensions)?;
+        info!("Find zipped shader pack {}", archive_path.to_str().unwrap());
+        shader_pack.update_properties();
+        shader_pack.update_dimensions();
+        Some(shader_pack)
+    }
+
+    pub(super) fn find_shader_packs(
+        shader_packs: &mut Vec<Rc<ShaderPack>>, extensions: &HashSet<String>, curr_path: PathBuf, is_root: bool,
+    ) {
         let file_name = curr_path.file_name().unwrap();
         if file_name == "shaders" {
             info!("Find shader pack {}", curr_path.to_str().unwrap());
             let debug = curr_path
                 .parent()
                 .and_then(|parent| parent.file_name())
-                .map_or(false, |name| name == "debug");
-            shader_packs.push(Rc::new(ShaderPack { path: curr_path, debug }));
-        } else if file_name
-            .to_str()
-            .map_or(true, |name| !name.starts_with('.') || name == ".minecraft")
-        {
+                .is_some_and(|name| name == "debug");
+            let shader_pack = ShaderPack::new(curr_path, debug);
+            shader_pack.update_properties();
+            shader_pack.update_dimensions();
+            shader_packs.push(Rc::new(shader_pack));
+        } else if file_name.to_str().is_none_or(|name| !name.starts_with('.') || name == ".minecraft") {
+            let is_archive_folder = Self::is_archive_folder(&curr_path, is_root);
             if let Ok(dir) = curr_path.read_dir() {
-                dir.filter_map(|file| file.ok())
-                    .filter(|file| file.file_type().unwrap().is_dir())
-                    .for_each(|file| {
-                        Self::find_shader_packs(shader_packs, file.path());
-                    })
+                dir.filter_map(|file| file.ok()).for_each(|file| {
+                    let file_path = file.path();
+                    if file.file_type().unwrap().is_dir() {
+                        Self::find_shader_packs(shader_packs, extensions, file_path, false);
+                    } else if is_archive_folder && file_path.extension().is_some_and(|ext| ext == "zip") {
+                        if let Some(shader_pack) = Self::load_shader_archive(extensions, &file_path) {
+                            shader_packs.push(Rc::new(shader_pack));
+                        }
+                    }
+                })
             }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn scan_files_in_root(
-        &self, parser: &mut Parser, shader_packs: &mut HashSet<Rc<ShaderPack>>,
+        &self, parser: &mut Parser, programs: &ProgramRegistry, extensions: &HashSet<String>, shader_packs: &mut HashSet<Rc<ShaderPack>>,
         workspace_files: &mut HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>, temp_files: &mut HashMap<PathBuf, TempFile>, root: PathBuf,
     ) {
         info!("Generating file framework on workspace \"{}\"", root.to_str().unwrap());
 
         let mut sub_shader_packs: Vec<Rc<ShaderPack>> = vec![];
-        Self::find_shader_packs(&mut sub_shader_packs, root);
+        Self::find_shader_packs(&mut sub_shader_packs, extensions, root, true);
 
         for shader_pack in &sub_shader_packs {
-            if let Ok(dir) = shader_pack.path.read_dir() {
-                dir.filter_map(|file| file.ok()).for_each(|file| {
-                    let file_path = file.path();
-                    if file.file_type().unwrap().is_file() {
-                        if RE_BASIC_SHADERS.is_match(file.file_name().to_str().unwrap()) {
-                            WorkspaceFile::new_shader(workspace_files, temp_files, parser, shader_pack, file_path);
-                        }
-                    } else if RE_DIMENSION_FOLDER.is_match(file.file_name().to_str().unwrap()) {
-                        file_path.read_dir().unwrap().filter_map(|file| file.ok()).for_each(|dim_file| {
-                            let dim_file_path = dim_file.path();
-                            if dim_file.file_type().unwrap().is_file() && RE_BASIC_SHADERS.is_match(dim_file.file_name().to_str().unwrap())
-                            {
-                                WorkspaceFile::new_shader(workspace_files, temp_files, parser, shader_pack, dim_file_path);
-                            }
-                        })
-                    }
-                })
-            }
+            Self::scan_shader_pack(parser, programs, workspace_files, temp_files, shader_pack);
         }
 
         shader_packs.extend(sub_shader_packs);
     }
 
+    /// Add programs in the root and dimension folders of this pack that are not added as shaders yet.
+    pub(super) fn scan_shader_pack(
+        parser: &mut Parser, programs: &ProgramRegistry, workspace_files: &mut HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>,
+        temp_files: &mut HashMap<PathBuf, TempFile>, shader_pack: &Rc<ShaderPack>,
+    ) {
+        let mut add_shader = |file_path: PathBuf| {
+            let is_shader = workspace_files.get(&file_path).is_some_and(|workspace_file| {
+                let file_type = *workspace_file.file_type().borrow();
+                file_type != gl::NONE && file_type != gl::INVALID_ENUM
+            });
+            if !is_shader {
+                WorkspaceFile::new_shader(workspace_files, temp_files, parser, shader_pack, file_path);
+            }
+        };
+        if let Some(archive) = &shader_pack.archive {
+            let mut file_paths = archive.list_folder(&shader_pack.path).cloned().collect::<Vec<_>>();
+            archive
+                .list_subfolders(&shader_pack.path)
+                .iter()
+                .filter(|folder| shader_pack.is_dimension_folder(folder.file_name().unwrap().to_str().unwrap()))
+                .for_each(|folder| file_paths.extend(archive.list_folder(folder).cloned()));
+            file_paths
+                .into_iter()
+                .filter(|file_path| programs.match_file(file_path.file_name().unwrap().to_str().unwrap()).is_some())
+                .for_each(add_shader);
+        } else if let Ok(dir) = shader_pack.path.read_dir() {
+            dir.filter_map(|file| file.ok()).for_each(|file| {
+                let file_path = file.path();
+                if file.file_type().unwrap().is_file() {
+                    if programs.match_file(file.file_name().to_str().unwrap()).is_some() {
+                        add_shader(file_path);
+                    }
+                } else if shader_pack.is_dimension_folder(file.file_name().to_str().unwrap()) {
+                    file_path.read_dir().unwrap().filter_map(|file| file.ok()).for_each(|dim_file| {
+                        if dim_file.file_type().unwrap().is_file() && programs.match_file(dim_file.file_name().to_str().unwrap()).is_some()
+                        {
+                            add_shader(dim_file.path());
+                        }
+                    })
+                }
+            })
+        }
+    }
+
     pub(super) fn lint_workspace_shader(
         &self, shader_file: &ShaderData, shader_path: &Rc<PathBuf>, update_list: &mut HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>,
     ) {
@@ -262,27 +350,111 @@ impl MinecraftLanguageServer {
         update_list
             .into_iter()
             .map(|(file_path, workspace_file)| {
-                let file_url = Url::from_file_path(file_path as &Path).unwrap();
-                let diagnostics = workspace_file
+                let file_url = path_to_url(file_path);
+                let mut diagnostics = workspace_file
                     .parent_shaders()
                     .borrow()
                     .values()
                     .flat_map(|(_, diagnostics)| diagnostics.borrow().clone())
                     .collect::<Vec<_>>();
+                let shader_pack = workspace_file.shader_pack();
+                diagnostics.extend(TreeParser::custom_uniform_lint(
+                    &workspace_file.tree().borrow(),
+                    &workspace_file.content().borrow(),
+                    &workspace_file.line_mapping().borrow(),
+                    &shader_pack.properties.borrow().custom_uniforms,
+                ));
+                diagnostics.extend(TreeParser::const_option_lint(
+                    &workspace_file.tree().borrow(),
+                    &workspace_file.content().borrow(),
+                    &workspace_file.line_mapping().borrow(),
+                ));
+                if let Some(pipeline_diagnostics) = shader_pack.pipeline_diagnostics.borrow().get(file_path as &PathBuf) {
+                    diagnostics.extend(pipeline_diagnostics.iter().cloned());
+                }
                 (file_url, diagnostics)
             })
             .collect()
     }
 
+    /// Rebuild render pipelines of this pack, and insert files whose pipeline diagnostics may change into update list.
+    pub(super) fn update_render_targets(
+        &self, shader_pack: &Rc<ShaderPack>, programs: &ProgramRegistry, workspace_files: &HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>,
+        update_list: &mut HashMap<Rc<PathBuf>, Rc<WorkspaceFile>>,
+    ) {
+        let pipelines = RenderPipeline::build(shader_pack, programs, workspace_files.iter());
+        let diagnostics = RenderPipeline::diagnostics(&pipelines);
+
+        let mut pipeline_diagnostics = shader_pack.pipeline_diagnostics.borrow_mut();
+        pipeline_diagnostics
+            .keys()
+            .chain(diagnostics.keys())
+            .filter_map(|file_path| workspace_files.get_key_value(file_path))
+            .for_each(|(file_path, workspace_file)| {
+                update_list.insert(file_path.clone(), workspace_file.clone());
+            });
+        *pipeline_diagnostics = diagnostics;
+    }
+
+    /// Replace the program registry, add files that become programs into shader packs and demote shaders no longer programs.
+    pub(super) fn update_programs(&self, server_data: &MutexGuard<ServerData>, new_programs: ProgramRegistry) -> Diagnostics {
+        let mut programs = server_data.programs.borrow_mut();
+        if *programs == new_programs {
+            return HashMap::new();
+        }
+        *programs = new_programs;
+
+        let mut parser = server_data.tree_sitter_parser.borrow_mut();
+        let shader_packs = server_data.shader_packs.borrow();
+        let mut workspace_files = server_data.workspace_files.borrow_mut();
+        let mut temp_files = server_data.temp_files.borrow_mut();
+
+        let mut update_list = HashMap::new();
+        for (file_path, workspace_file) in workspace_files.iter() {
+            let file_type = *workspace_file.file_type().borrow();
+            if file_type != gl::NONE
+                && file_type != gl::INVALID_ENUM
+                && Self::shader_program(&programs, workspace_file, file_path).is_none()
+            {
+                workspace_file.demote_shader(&mut update_list);
+                update_list.insert(file_path.clone(), workspace_file.clone());
+            }
+        }
+        for shader_pack in shader_packs.iter() {
+            Self::scan_shader_pack(&mut parser, &programs, &mut workspace_files, &mut temp_files, shader_pack);
+            self.update_render_targets(shader_pack, &programs, &workspace_files, &mut update_list);
+        }
+        self.collect_diagnostics(&update_list)
+    }
+
+    pub(super) fn collect_properties_diagnostics<'a>(&self, shader_packs: impl Iterator<Item = &'a Rc<ShaderPack>>) -> Diagnostics {
+        shader_packs
+            .map(|shader_pack| {
+                let file_url = path_to_url(&shader_pack.properties_path());
+                (file_url, shader_pack.properties.borrow().diagnostics.clone())
+            })
+            .collect()
+    }
+
     pub(super) fn initial_scan(&self, roots: Vec<PathBuf>) {
         let server_data = self.server_data.lock().unwrap();
         let mut parser = server_data.tree_sitter_parser.borrow_mut();
         let mut shader_packs = server_data.shader_packs.borrow_mut();
         let mut workspace_files = server_data.workspace_files.borrow_mut();
         let mut temp_files = server_data.temp_files.borrow_mut();
+        let programs = server_data.programs.borrow();
+        let extensions = server_data.extensions.borrow();
 
         for root in roots {
-            self.scan_files_in_root(&mut parser, &mut shader_packs, &mut workspace_files, &mut temp_files, root);
+            self.scan_files_in_root(
+                &mut parser,
+                &programs,
+                &extensions,
+                &mut shader_packs,
+                &mut workspace_files,
+                &mut temp_files,
+                root,
+            );
         }
     }
 }
@@ -0,0 +1,74 @@
+use super::*;
+
+/// Case-insensitive subsequence match of `query` in `name`, scoring lower for fewer gaps between matched characters
+fn fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    let mut score = 0;
+    let mut gap = 0;
+    let mut name_chars = name.chars();
+    for query_char in query.chars() {
+        loop {
+            let name_char = name_chars.next()?;
+            if name_char.eq_ignore_ascii_case(&query_char) {
+                score += gap.min(1);
+                gap = 0;
+                break;
+            }
+            gap += 1;
+        }
+    }
+    Some(score)
+}
+
+/// Add this symbol and its children, skipping those not matching `query`
+fn push_matches(symbol: DocumentSymbol, query: &str, url: &Url, pack_name: &str, symbols: &mut Vec<(usize, SymbolInformation)>) {
+    if let Some(score) = fuzzy_score(query, &symbol.name) {
+        #[allow(deprecated)]
+        symbols.push((
+            score,
+            SymbolInformation {
+                name: symbol.name,
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                location: Location {
+                    uri: url.clone(),
+                    range: symbol.selection_range,
+                },
+                container_name: Some(pack_name.to_owned()),
+            },
+        ));
+    }
+    for child in symbol.children.into_iter().flatten() {
+        push_matches(child, query, url, pack_name, symbols);
+    }
+}
+
+impl MinecraftLanguageServer {
+    pub fn workspace_symbol(&self, params: WorkspaceSymbolParams) -> Option<Vec<SymbolInformation>> {
+        let server_data = self.server_data.lock().unwrap();
+        let workspace_files = server_data.workspace_files.borrow();
+
+        let mut symbols = vec![];
+        for (file_path, workspace_file) in workspace_files.iter() {
+            let shader_pack = workspace_file.shader_pack();
+            let pack_name = shader_pack.path.file_name().unwrap_or_default().to_string_lossy();
+            let url = path_to_url(file_path);
+
+            let content = workspace_file.content().borrow();
+            let tree = workspace_file.tree().borrow();
+            let line_mapping = workspace_file.line_mapping().borrow();
+            for symbol in TreeParser::list_symbols(&tree, &content, &line_mapping) {
+                push_matches(symbol, &params.query, &url, &pack_name, &mut symbols);
+            }
+        }
+
+        // Closer matches first, then in order of files and lines
+        symbols.sort_by(|(score, symbol), (other_score, other)| {
+            score
+                .cmp(other_score)
+                .then_with(|| symbol.location.uri.as_str().cmp(other.location.uri.as_str()))
+                .then_with(|| symbol.location.range.start.cmp(&other.location.range.start))
+        });
+        Some(symbols.into_iter().map(|(_, symbol)| symbol).collect())
+    }
+}
@@ -0,0 +1,41 @@
+use super::definition::Definition;
+use super::*;
+
+/// A function definition and the functions called in its body
+pub struct FunctionCalls {
+    pub name: String,
+    /// Range of the whole definition
+    pub range: Range,
+    /// Range of the function name
+    pub selection_range: Range,
+    /// Names of called functions and ranges of the names at call sites, in document order
+    pub calls: Vec<(String, Range)>,
+}
+
+impl TreeParser {
+    /// Function definitions of this file with their calls, including those inside preprocessor conditions
+    pub fn function_calls(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<FunctionCalls> {
+        Self::global_definitions(tree)
+            .into_iter()
+            .filter(|definition| definition.node.kind() == "function_definition")
+            .map(|Definition { name, node, .. }| {
+                let calls = named_descendants(node)
+                    .into_iter()
+                    .filter(|call| call.kind() == "call_expression")
+                    .filter_map(|call| call.child_by_field_name("function"))
+                    .filter(|function| function.kind() == "identifier")
+                    .map(|function| {
+                        let callee = function.utf8_text(content.as_bytes()).unwrap().to_owned();
+                        (callee, function.to_range(content, line_mapping))
+                    })
+                    .collect();
+                FunctionCalls {
+                    name: name.utf8_text(content.as_bytes()).unwrap().to_owned(),
+                    range: node.to_range(content, line_mapping),
+                    selection_range: name.to_range(content, line_mapping),
+                    calls,
+                }
+            })
+            .collect()
+    }
+}
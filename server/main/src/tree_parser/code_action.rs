@@ -0,0 +1,127 @@
+use super::*;
+
+/// Texture functions removed from core profile, and their replacements
+const LEGACY_TEXTURE_FUNCTIONS: &[(&str, &str)] = &[
+    ("texture1D", "texture"),
+    ("texture2D", "texture"),
+    ("texture3D", "texture"),
+    ("textureCube", "texture"),
+    ("texture1DLod", "textureLod"),
+    ("texture2DLod", "textureLod"),
+    ("texture3DLod", "textureLod"),
+    ("textureCubeLod", "textureLod"),
+    ("texture1DProj", "textureProj"),
+    ("texture2DProj", "textureProj"),
+    ("texture3DProj", "textureProj"),
+    ("texture2DProjLod", "textureProjLod"),
+    ("texture2DGradARB", "textureGrad"),
+];
+
+/// An identifier used in code without a definition in its scopes
+pub struct UnresolvedName {
+    pub name: String,
+    pub range: Range,
+    /// Whether it is the function of a call
+    pub is_call: bool,
+}
+
+impl TreeParser {
+    /// Identifiers in `rows` that are not defined locally, callers check global definitions and built-ins
+    pub fn unresolved_names(tree: &Tree, content: &str, line_mapping: &[usize], rows: std::ops::Range<usize>) -> Vec<UnresolvedName> {
+        named_descendants(tree.root_node())
+            .into_iter()
+            .filter(|node| node.kind() == "identifier" && rows.contains(&node.start_position().row))
+            .filter_map(|node| {
+                let parent = node.parent()?;
+                if parent.kind().starts_with("preproc") || Self::local_definition(node, content).is_some() {
+                    return None;
+                }
+                Some(UnresolvedName {
+                    name: node.utf8_text(content.as_bytes()).unwrap().to_owned(),
+                    range: node.to_range(content, line_mapping),
+                    is_call: parent.kind() == "call_expression" && parent.child_by_field_name("function") == Some(node),
+                })
+            })
+            .collect()
+    }
+
+    /// Line to insert new global declarations and includes, after `#version`, `#extension` and `#include` directives at the start
+    pub fn declaration_line(content: &str) -> u32 {
+        let mut line = 0;
+        for (row, text) in content.lines().enumerate() {
+            let text = text.trim();
+            let directive = text.strip_prefix('#').map(str::trim_start);
+            if directive.is_some_and(|directive| {
+                ["version", "extension", "include", "moj_import"]
+                    .iter()
+                    .any(|name| directive.starts_with(name))
+            }) {
+                line = row as u32 + 1;
+            } else if !text.is_empty() && !text.starts_with("//") {
+                break;
+            }
+        }
+        line
+    }
+
+    /// Edits replacing calls of legacy texture functions in this file with core profile functions, with the replaced names
+    pub fn legacy_texture_edits(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<(&'static str, TextEdit)> {
+        named_descendants(tree.root_node())
+            .into_iter()
+            .filter(|node| node.kind() == "call_expression")
+            .filter_map(|call| call.child_by_field_name("function"))
+            .filter_map(|function| {
+                let name = function.utf8_text(content.as_bytes()).unwrap();
+                let (legacy, replacement) = LEGACY_TEXTURE_FUNCTIONS.iter().find(|(legacy, _)| *legacy == name)?;
+                let edit = TextEdit {
+                    range: function.to_range(content, line_mapping),
+                    new_text: replacement.to_string(),
+                };
+                Some((*legacy, edit))
+            })
+            .collect()
+    }
+
+    /// Edits replacing `gl_FragData[N]` and `gl_FragColor` with declared outputs, `None` if some index is not a constant
+    pub fn fragment_data_edits(tree: &Tree, content: &str, line_mapping: &[usize]) -> Option<Vec<TextEdit>> {
+        let outputs = Self::fragment_outputs(tree, content, line_mapping)
+            .into_iter()
+            .filter(|output| !output.declared)
+            .collect::<Vec<_>>();
+        if outputs.is_empty() {
+            return None;
+        }
+
+        let mut locations = vec![];
+        let mut edits = vec![];
+        for output in outputs {
+            let location = output.location?;
+            let name = format!("outColor{}", location);
+            if Self::defines(&name, tree, content) {
+                return None;
+            }
+            if !locations.contains(&location) {
+                locations.push(location);
+            }
+            edits.push(TextEdit {
+                range: output.range,
+                new_text: name,
+            });
+        }
+        locations.sort_unstable();
+
+        let declarations = locations
+            .iter()
+            .map(|location| format!("layout(location = {}) out vec4 outColor{};\n", location, location))
+            .collect::<String>();
+        let line = Self::declaration_line(content);
+        edits.insert(
+            0,
+            TextEdit {
+                range: Range::new(Position::new(line, 0), Position::new(line, 0)),
+                new_text: declarations,
+            },
+        );
+        Some(edits)
+    }
+}
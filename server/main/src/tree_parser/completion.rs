@@ -0,0 +1,125 @@
+use super::definition::Definition;
+use super::*;
+
+impl Definition<'_> {
+    /// Completion item of this definition, `sort_prefix` ranks items from nearer scopes first
+    fn completion_item(&self, content: &str, sort_prefix: char) -> CompletionItem {
+        let kind = match self.node.kind() {
+            "function_definition" | "preproc_function_def" => CompletionItemKind::FUNCTION,
+            "struct_specifier" => CompletionItemKind::STRUCT,
+            "preproc_def" => CompletionItemKind::CONSTANT,
+            _ => CompletionItemKind::VARIABLE,
+        };
+        let label = self.name.utf8_text(content.as_bytes()).unwrap().to_owned();
+        CompletionItem {
+            sort_text: Some(format!("{}{}", sort_prefix, label)),
+            label,
+            kind: Some(kind),
+            detail: Some(self.signature(content)),
+            documentation: self.documentation(content).map(|value| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value,
+                })
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// Whether this block is closed by a `}` before the offset
+fn closed_before(block: Node, offset: usize) -> bool {
+    block.end_byte() <= offset
+        && block
+            .child(block.child_count().saturating_sub(1))
+            .is_some_and(|last| last.kind() == "}" && !last.is_missing())
+}
+
+impl TreeParser {
+    /// Completion items of local variables and parameters visible at this position.
+    ///
+    /// Returns `None` if no identifier should be completed here, like in comments, strings or after `.`.
+    pub fn local_completion(position: Position, tree: &Tree, content: &str, line_mapping: &[usize]) -> Option<Vec<CompletionItem>> {
+        let offset = byte_index(content, position, line_mapping).0;
+        let prefix_start = content[..offset]
+            .trim_end_matches(|char: char| char.is_ascii_alphanumeric() || char == '_')
+            .len();
+        if content[..prefix_start].ends_with('.') {
+            return None;
+        }
+
+        let node = tree.root_node().descendant_for_byte_range(prefix_start, offset)?;
+        let mut current = Some(node);
+        while let Some(ancestor) = current {
+            match ancestor.kind() {
+                "string_literal" | "system_lib_string" | "char_literal" | "number_literal" | "preproc_include" => return None,
+                // Line comments end at the end of line, where the cursor is still in the comment
+                "comment" if offset < ancestor.end_byte() || !ancestor.utf8_text(content.as_bytes()).unwrap().starts_with("/*") => {
+                    return None
+                }
+                _ => {}
+            }
+            current = ancestor.parent();
+        }
+
+        let mut items = vec![];
+        let mut current = Some(node);
+        while let Some(scope) = current {
+            match scope.kind() {
+                "compound_statement" | "for_statement" if !closed_before(scope, prefix_start) => {
+                    let mut cursor = scope.walk();
+                    items.extend(
+                        scope
+                            .named_children(&mut cursor)
+                            .filter(|child| child.start_byte() < prefix_start)
+                            .flat_map(Definition::from_declaration)
+                            .filter(|definition| definition.name.end_byte() < prefix_start)
+                            .map(|definition| definition.completion_item(content, '0')),
+                    );
+                }
+                // Unclosed function bodies are parsed as errors holding the declarator and statements
+                "ERROR" => {
+                    let mut cursor = scope.walk();
+                    let children = scope
+                        .named_children(&mut cursor)
+                        .filter(|child| child.start_byte() < prefix_start)
+                        .collect::<Vec<_>>();
+                    for child in children {
+                        let definitions = match child.kind() {
+                            "function_declarator" => Definition::parameters(child),
+                            _ => Definition::from_declaration(child),
+                        };
+                        items.extend(
+                            definitions
+                                .iter()
+                                .filter(|definition| definition.name.end_byte() < prefix_start)
+                                .map(|definition| definition.completion_item(content, '0')),
+                        );
+                    }
+                }
+                "function_definition" => {
+                    let body = scope.child_by_field_name("body");
+                    if !body.is_some_and(|body| closed_before(body, prefix_start)) {
+                        items.extend(
+                            Definition::parameters(scope)
+                                .iter()
+                                .map(|definition| definition.completion_item(content, '0')),
+                        );
+                    }
+                    break;
+                }
+                _ => {}
+            }
+            current = scope.parent();
+        }
+        Some(items)
+    }
+
+    /// Completion items of functions, global variables, structs and macros in this file
+    pub fn global_completion(tree: &Tree, content: &str) -> Vec<CompletionItem> {
+        Self::global_definitions(tree)
+            .iter()
+            .map(|definition| definition.completion_item(content, '1'))
+            .collect()
+    }
+}
@@ -0,0 +1,326 @@
+use hashbrown::HashMap;
+use regex::Regex;
+
+use super::*;
+
+/// Options with fixed names, as (name, type, default value, description)
+#[rustfmt::skip]
+static FIXED_OPTIONS: &[(&str, &str, &str, &str)] = &[
+    ("shadowMapResolution", "int", "2048", "Resolution of the shadow map."),
+    ("shadowDistance", "float", "160.0", "Distance in blocks covered by the shadow map."),
+    ("shadowDistanceRenderMul", "float", "-1.0", "Render distance of shadow casters as a multiplier of `shadowDistance`. Negative values render all loaded chunks."),
+    ("shadowIntervalSize", "float", "2.0", "Step size in blocks when the shadow map follows the player, reduces shadow flickering."),
+    ("generateShadowMipmap", "bool", "false", "Generate mipmaps for shadow depth textures."),
+    ("generateShadowColorMipmap", "bool", "false", "Generate mipmaps for shadow color textures."),
+    ("shadowHardwareFiltering", "bool", "false", "Enable hardware depth comparison for all shadow depth textures, required by `shadow2D`."),
+    ("shadowHardwareFiltering0", "bool", "false", "Enable hardware depth comparison for `shadowtex0`."),
+    ("shadowHardwareFiltering1", "bool", "false", "Enable hardware depth comparison for `shadowtex1`."),
+    ("shadowtexMipmap", "bool", "false", "Enable mipmaps for `shadowtex0`."),
+    ("shadowtex0Mipmap", "bool", "false", "Enable mipmaps for `shadowtex0`."),
+    ("shadowtex1Mipmap", "bool", "false", "Enable mipmaps for `shadowtex1`."),
+    ("shadowcolor0Mipmap", "bool", "false", "Enable mipmaps for `shadowcolor0`."),
+    ("shadowColor0Mipmap", "bool", "false", "Enable mipmaps for `shadowcolor0`."),
+    ("shadowcolor1Mipmap", "bool", "false", "Enable mipmaps for `shadowcolor1`."),
+    ("shadowColor1Mipmap", "bool", "false", "Enable mipmaps for `shadowcolor1`."),
+    ("shadowtexNearest", "bool", "false", "Use nearest filtering for `shadowtex0`."),
+    ("shadowtex0Nearest", "bool", "false", "Use nearest filtering for `shadowtex0`."),
+    ("shadow0MinMagNearest", "bool", "false", "Use nearest filtering for `shadowtex0`."),
+    ("shadowtex1Nearest", "bool", "false", "Use nearest filtering for `shadowtex1`."),
+    ("shadow1MinMagNearest", "bool", "false", "Use nearest filtering for `shadowtex1`."),
+    ("shadowcolor0Nearest", "bool", "false", "Use nearest filtering for `shadowcolor0`."),
+    ("shadowColor0Nearest", "bool", "false", "Use nearest filtering for `shadowcolor0`."),
+    ("shadowColor0MinMagNearest", "bool", "false", "Use nearest filtering for `shadowcolor0`."),
+    ("shadowcolor1Nearest", "bool", "false", "Use nearest filtering for `shadowcolor1`."),
+    ("shadowColor1Nearest", "bool", "false", "Use nearest filtering for `shadowcolor1`."),
+    ("shadowColor1MinMagNearest", "bool", "false", "Use nearest filtering for `shadowcolor1`."),
+    ("wetnessHalflife", "float", "600.0", "Half-life in ticks of `wetness` when rain starts."),
+    ("drynessHalflife", "float", "200.0", "Half-life in ticks of `wetness` when rain stops."),
+    ("eyeBrightnessHalflife", "float", "10.0", "Half-life in ticks of `eyeBrightnessSmooth`."),
+    ("centerDepthHalflife", "float", "1.0", "Half-life in ticks of `centerDepthSmooth`."),
+    ("sunPathRotation", "float", "0.0", "Angle in degrees the sun path is tilted from the zenith."),
+    ("ambientOcclusionLevel", "float", "1.0", "Strength of vanilla ambient occlusion, from 0.0 to 1.0."),
+    ("superSamplingLevel", "int", "1", "Super sampling level of the whole render pipeline."),
+    ("noiseTextureResolution", "int", "256", "Resolution of the `noisetex` texture."),
+];
+
+static BUFFER_NAMES: &[&str] = &["gcolor", "gdepth", "gnormal", "composite", "gaux1", "gaux2", "gaux3", "gaux4"];
+
+static BUFFER_OPTION_SUFFIXES: &[&str] = &["Format", "Clear", "ClearColor", "MipmapEnabled"];
+
+/// Texture formats with special layouts, as (name, description)
+static SPECIAL_FORMATS: &[(&str, &str)] = &[
+    ("R3_G3_B2", "3-bit red and green, 2-bit blue, unsigned normalized"),
+    ("RGB4", "3 channels, 4-bit unsigned normalized"),
+    ("RGB5", "3 channels, 5-bit unsigned normalized"),
+    ("RGB10", "3 channels, 10-bit unsigned normalized"),
+    ("RGB12", "3 channels, 12-bit unsigned normalized"),
+    ("RGBA2", "4 channels, 2-bit unsigned normalized"),
+    ("RGBA4", "4 channels, 4-bit unsigned normalized"),
+    ("RGB5_A1", "5-bit color and 1-bit alpha, unsigned normalized"),
+    ("RGB10_A2", "10-bit color and 2-bit alpha, unsigned normalized"),
+    ("RGBA12", "4 channels, 12-bit unsigned normalized"),
+    ("R11F_G11F_B10F", "11-bit red and green, 10-bit blue, unsigned float"),
+    ("RGB9_E5", "3 channels, 9-bit mantissa with shared 5-bit exponent"),
+];
+
+lazy_static! {
+    static ref RE_BUFFER_OPTION: Regex =
+        Regex::new(r"^(colortex(?:[0-9]|1[0-5])|gcolor|gdepth|gnormal|composite|gaux[1-4]|shadowcolor[0-7])(Format|Clear|ClearColor|MipmapEnabled)$")
+            .unwrap();
+    static ref RE_COMMENT_OPTION: Regex =
+        Regex::new(r"(?m)^[ \t]*(?://+|/\*+)?[ \t]*const[ \t]+(\w+)[ \t]+(\w+)[ \t]*=[ \t]*([^;\r\n]*?)[ \t]*;").unwrap();
+    static ref RE_REGULAR_FORMAT: Regex = Regex::new(r"^(RGBA|RGB|RG|R)(8|16|32)(_SNORM|F|I|UI)?$").unwrap();
+    /// All option names, used to find the intended name of a misspelled option
+    static ref OPTION_NAMES: Vec<String> = {
+        let buffers = (0..16)
+            .map(|index| format!("colortex{}", index))
+            .chain((0..8).map(|index| format!("shadowcolor{}", index)))
+            .chain(BUFFER_NAMES.iter().map(|name| name.to_string()));
+        FIXED_OPTIONS
+            .iter()
+            .map(|(name, _, _, _)| name.to_string())
+            .chain(buffers.flat_map(|buffer| BUFFER_OPTION_SUFFIXES.iter().map(move |suffix| buffer.clone() + suffix)))
+            .collect()
+    };
+    static ref TEXTURE_FORMATS: HashMap<String, String> = {
+        let mut formats = HashMap::new();
+        for channels in ["R", "RG", "RGB", "RGBA"] {
+            for suffix in ["8", "8_SNORM", "16", "16_SNORM", "16F", "32F", "8I", "8UI", "16I", "16UI", "32I", "32UI"] {
+                let name = channels.to_owned() + suffix;
+                let description = texture_format_description(&name);
+                formats.insert(name, description);
+            }
+        }
+        formats.extend(SPECIAL_FORMATS.iter().map(|(name, description)| (name.to_string(), description.to_string())));
+        formats
+    };
+}
+
+fn texture_format_description(name: &str) -> String {
+    let captures = RE_REGULAR_FORMAT.captures(name).unwrap();
+    let channels = captures.get(1).unwrap().as_str().len();
+    let bits = captures.get(2).unwrap().as_str();
+    let kind = match captures.get(3).map(|suffix| suffix.as_str()) {
+        Some("_SNORM") => "signed normalized",
+        Some("F") => "float",
+        Some("I") => "signed integer",
+        Some("UI") => "unsigned integer",
+        _ => "unsigned normalized",
+    };
+    match channels {
+        1 => format!("1 channel, {}-bit {}", bits, kind),
+        _ => format!("{} channels, {}-bit {}", channels, bits, kind),
+    }
+}
+
+/// Known option info, as (type, default value, description)
+fn option_info(name: &str) -> Option<(&'static str, Option<&'static str>, String)> {
+    if let Some((_, type_name, default, description)) = FIXED_OPTIONS.iter().find(|option| option.0 == name) {
+        return Some((type_name, Some(default), description.to_string()));
+    }
+    let captures = RE_BUFFER_OPTION.captures(name)?;
+    let buffer = captures.get(1).unwrap().as_str();
+    Some(match captures.get(2).unwrap().as_str() {
+        "Format" => ("int", None, format!("Internal texture format of `{}`.", buffer)),
+        "Clear" => (
+            "bool",
+            Some("true"),
+            format!(
+                "Clear `{}` every frame. Set to `false` to keep its content from the last frame.",
+                buffer
+            ),
+        ),
+        "ClearColor" => ("vec4", None, format!("Color used to clear `{}`.", buffer)),
+        _ => ("bool", Some("false"), format!("Generate mipmaps for `{}`.", buffer)),
+    })
+}
+
+/// Levenshtein distance of two ASCII strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, char_a) in a.bytes().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, char_b) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = (previous + (char_a != *char_b) as usize).min(row[j] + 1).min(current + 1);
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+/// Find a known name close enough to be the intended one
+fn similar_name<'a>(name: &str, candidates: impl Iterator<Item = &'a String>) -> Option<&'a String> {
+    // Short names like `PI` are too easy to be close to something
+    if name.len() < 8 {
+        return None;
+    }
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Whether this declaration may be a misspelling of this option, instead of a constant named after it like `shadowDistanceSq`
+fn may_misspell(option: &ConstOption, candidate: &str) -> bool {
+    let (name, candidate_lowercase) = (option.name.to_lowercase(), candidate.to_lowercase());
+    if name.starts_with(&candidate_lowercase) || candidate_lowercase.starts_with(&name) {
+        return false;
+    }
+    let type_name = match option_info(candidate) {
+        Some((type_name, _, _)) if type_name == option.type_name => type_name,
+        _ => return false,
+    };
+    let value = option.value.as_str();
+    match type_name {
+        "bool" => matches!(value, "true" | "false"),
+        "int" if candidate.ends_with("Format") => value.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'_'),
+        "int" => value.parse::<i64>().is_ok(),
+        "float" => value.trim_end_matches(['f', 'F']).parse::<f64>().is_ok(),
+        _ => value.starts_with(type_name),
+    }
+}
+
+/// A `const` declaration in code or in comments, which may be read as an OptiFine option
+pub struct ConstOption {
+    pub type_name: String,
+    pub name: String,
+    pub value: String,
+    pub type_range: Range,
+    pub name_range: Range,
+    pub value_range: Range,
+}
+
+impl TreeParser {
+    /// Global `const` declarations and `const` lines in comments, the game reads options from both of them.
+    pub fn const_options(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<ConstOption> {
+        let mut options = vec![];
+        for declaration in Self::global_declarations(tree) {
+            if !declaration.has_qualifier("const") {
+                continue;
+            }
+            for (identifier, declarator) in &declaration.declarators {
+                let value = match declarator.child_by_field_name("value") {
+                    Some(value) => value,
+                    None => continue,
+                };
+                options.push(ConstOption {
+                    type_name: declaration.type_node.utf8_text(content.as_bytes()).unwrap().to_owned(),
+                    name: identifier.utf8_text(content.as_bytes()).unwrap().to_owned(),
+                    value: value.utf8_text(content.as_bytes()).unwrap().to_owned(),
+                    type_range: declaration.type_node.to_range(content, line_mapping),
+                    name_range: identifier.to_range(content, line_mapping),
+                    value_range: value.to_range(content, line_mapping),
+                });
+            }
+        }
+
+        let mut query_cursor = QueryCursor::new();
+        let query = Query::new(tree_sitter_glsl::language(), "(comment) @comment").unwrap();
+        for query_match in query_cursor.matches(&query, tree.root_node(), content.as_bytes()) {
+            let node = query_match.captures[0].node;
+            let text = node.utf8_text(content.as_bytes()).unwrap();
+            for captures in RE_COMMENT_OPTION.captures_iter(text) {
+                let range = |index: usize| {
+                    let capture = captures.get(index).unwrap();
+                    Range {
+                        start: Self::offset_position(content, line_mapping, node.start_byte() + capture.start()),
+                        end: Self::offset_position(content, line_mapping, node.start_byte() + capture.end()),
+                    }
+                };
+                options.push(ConstOption {
+                    type_name: captures.get(1).unwrap().as_str().to_owned(),
+                    name: captures.get(2).unwrap().as_str().to_owned(),
+                    value: captures.get(3).unwrap().as_str().to_owned(),
+                    type_range: range(1),
+                    name_range: range(2),
+                    value_range: range(3),
+                });
+            }
+        }
+        options
+    }
+
+    /// Check names, types and texture formats of OptiFine const options. The game ignores invalid ones silently.
+    pub fn const_option_lint(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        let mut push_diagnostic = |range: Range, message: String| {
+            diagnostics.push(Diagnostic {
+                range,
+                severity: Some(DiagnosticSeverity::WARNING),
+                source: Some("mcshader-glsl".to_owned()),
+                message,
+                ..Default::default()
+            })
+        };
+
+        for option in Self::const_options(tree, content, line_mapping) {
+            let (type_name, _, _) = match option_info(&option.name) {
+                Some(info) => info,
+                None => {
+                    let candidates = OPTION_NAMES.iter().filter(|candidate| may_misspell(&option, candidate));
+                    if let Some(name) = similar_name(&option.name, candidates) {
+                        let message = format!(
+                            "Unknown option `{}` will be ignored by the game, did you mean `{}`?",
+                            option.name, name
+                        );
+                        push_diagnostic(option.name_range, message);
+                    }
+                    continue;
+                }
+            };
+            if option.type_name != type_name {
+                let message = format!(
+                    "Option `{}` should be declared as `{}`, it will be ignored by the game",
+                    option.name, type_name
+                );
+                push_diagnostic(option.type_range, message);
+            } else if option.name.ends_with("Format") && !TEXTURE_FORMATS.contains_key(&option.value) {
+                let uppercase = option.value.to_uppercase();
+                let similar_format = match TEXTURE_FORMATS.get_key_value(&uppercase) {
+                    Some((format, _)) => Some(format),
+                    None => similar_name(&option.value, TEXTURE_FORMATS.keys()),
+                };
+                let message = match similar_format {
+                    Some(format) => format!("Unknown texture format `{}`, did you mean `{}`?", option.value, format),
+                    None => format!("Unknown texture format `{}`", option.value),
+                };
+                push_diagnostic(option.value_range, message);
+            }
+        }
+        diagnostics
+    }
+
+    /// Documentation of the OptiFine option or texture format under the cursor
+    pub fn const_option_hover(position: Position, tree: &Tree, content: &str, line_mapping: &[usize]) -> Option<Hover> {
+        let contains = |range: &Range| range.start <= position && position < range.end;
+        let option = Self::const_options(tree, content, line_mapping)
+            .into_iter()
+            .find(|option| contains(&option.name_range) || contains(&option.value_range))?;
+
+        let (value, range) = if contains(&option.name_range) {
+            let (type_name, default, description) = option_info(&option.name)?;
+            let declaration = match default {
+                Some(default) => format!("const {} {} = {};", type_name, option.name, default),
+                None => format!("const {} {};", type_name, option.name),
+            };
+            (
+                format!("```glsl\n{}\n```\n---\nOptiFine option. {}", declaration, description),
+                option.name_range,
+            )
+        } else {
+            let description = TEXTURE_FORMATS.get(&option.value).filter(|_| option.name.ends_with("Format"))?;
+            (format!("Texture format `{}`: {}", option.value, description), option.value_range)
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: Some(range),
+        })
+    }
+}
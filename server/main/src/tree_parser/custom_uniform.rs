@@ -0,0 +1,70 @@
+use hashbrown::HashMap;
+
+use crate::properties::CustomUniform;
+
+use super::*;
+
+impl TreeParser {
+    /// Top level `uniform` declarations in this file, as (identifier, type, declarator)
+    pub fn uniform_declarations<'a>(tree: &'a Tree, content: &str) -> Vec<(Node<'a>, Node<'a>, Node<'a>)> {
+        Self::global_declarations(tree)
+            .into_iter()
+            .filter(|declaration| declaration.has_qualifier("uniform"))
+            .flat_map(|declaration| {
+                let type_node = declaration.type_node;
+                declaration
+                    .declarators
+                    .into_iter()
+                    .filter(|(identifier, _)| !identifier.utf8_text(content.as_bytes()).unwrap().is_empty())
+                    .map(move |(identifier, declarator)| (identifier, type_node, declarator))
+            })
+            .collect()
+    }
+
+    /// Returns the name of uniform if the cursor is on the declarator of a top level uniform declaration
+    pub fn uniform_declaration_name<'a>(position: Position, tree: &Tree, content: &'a str, line_mapping: &[usize]) -> Option<&'a str> {
+        let current_node = Self::current_node_fetch(position, tree, content, line_mapping)?;
+        Self::uniform_declarations(tree, content)
+            .into_iter()
+            .find(|(identifier, _, _)| *identifier == current_node)
+            .map(|(identifier, _, _)| identifier.utf8_text(content.as_bytes()).unwrap())
+    }
+
+    /// Check GLSL uniform declarations against custom uniforms from `shaders.properties`
+    pub fn custom_uniform_lint(
+        tree: &Tree, content: &str, line_mapping: &[usize], custom_uniforms: &HashMap<String, CustomUniform>,
+    ) -> Vec<Diagnostic> {
+        if custom_uniforms.is_empty() {
+            return vec![];
+        }
+        Self::uniform_declarations(tree, content)
+            .into_iter()
+            .filter_map(|(identifier, type_node, declarator)| {
+                let name = identifier.utf8_text(content.as_bytes()).unwrap();
+                let custom_uniform = custom_uniforms.get(name)?;
+                let type_name = type_node.utf8_text(content.as_bytes()).unwrap();
+                let message = if !custom_uniform.is_uniform {
+                    format!(
+                        "`{}` is declared as a variable in shaders.properties and will not be passed to shaders",
+                        name
+                    )
+                } else if declarator.kind() == "array_declarator" || type_name != custom_uniform.uniform_type.name() {
+                    format!(
+                        "Custom uniform `{}` is declared as `{}` in shaders.properties, this declaration will get a zero value",
+                        name,
+                        custom_uniform.uniform_type.name()
+                    )
+                } else {
+                    return None;
+                };
+                Some(Diagnostic {
+                    range: identifier.to_range(content, line_mapping),
+                    severity: Some(DiagnosticSeverity::WARNING),
+                    source: Some("mcshader-glsl".to_owned()),
+                    message,
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+}
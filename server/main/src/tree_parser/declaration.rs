@@ -0,0 +1,82 @@
+use super::*;
+
+/// A variable declaration split into parts.
+///
+/// tree-sitter-glsl shares the C grammar, so GLSL storage qualifiers shift the `type` and `declarator` fields.
+/// For example, `out vec4 color;` puts `out` in `type` and `vec4` in `declarator`.
+/// This struct collects the real parts by node kinds instead of field names.
+pub struct Declaration<'a> {
+    /// Qualifier nodes before the type, such as `uniform`, `const`, `flat` or `layout(...)`
+    pub qualifiers: Vec<Node<'a>>,
+    pub type_node: Node<'a>,
+    /// Declared identifiers and their declarators (`identifier`, `init_declarator` or `array_declarator`)
+    pub declarators: Vec<(Node<'a>, Node<'a>)>,
+}
+
+impl<'a> Declaration<'a> {
+    pub fn new(node: Node<'a>) -> Option<Self> {
+        if node.kind() != "declaration" {
+            return None;
+        }
+        let mut qualifiers = vec![];
+        let mut type_node = None;
+        let mut declarators = vec![];
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match (child.kind(), type_node) {
+                ("primitive_type" | "type_identifier" | "struct_specifier", None) => type_node = Some(child),
+                (_, None) if child.kind() != "comment" => qualifiers.push(child),
+                ("identifier", Some(_)) => declarators.push((child, child)),
+                ("init_declarator" | "array_declarator", Some(_)) => {
+                    if let Some(identifier) = Self::declarator_identifier(child) {
+                        declarators.push((identifier, child));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Some(Declaration {
+            qualifiers,
+            type_node: type_node?,
+            declarators,
+        })
+    }
+
+    /// Identifier inside a declarator like `init_declarator` or `array_declarator`
+    pub fn declarator_identifier(mut node: Node<'a>) -> Option<Node<'a>> {
+        while node.kind() != "identifier" {
+            node = node.child_by_field_name("declarator")?;
+        }
+        Some(node)
+    }
+
+    pub fn has_qualifier(&self, qualifier: &str) -> bool {
+        self.qualifiers.iter().any(|node| match node.kind() {
+            "type_qualifier" => node.child(0).is_some_and(|child| child.kind() == qualifier),
+            kind => kind == qualifier,
+        })
+    }
+}
+
+impl TreeParser {
+    /// Global variable declarations in this file, including those wrapped by preprocessor conditions
+    pub fn global_declarations(tree: &Tree) -> Vec<Declaration<'_>> {
+        let root_node = tree.root_node();
+        let mut cursor = root_node.walk();
+        let mut declarations = vec![];
+        let mut stack = vec![root_node];
+        // Declarations wrapped by `#ifdef` are also top level in GLSL
+        while let Some(node) = stack.pop() {
+            for child in node.named_children(&mut cursor) {
+                match child.kind() {
+                    "declaration" => declarations.extend(Declaration::new(child)),
+                    "preproc_if" | "preproc_ifdef" | "preproc_else" | "preproc_elif" | "preproc_elifdef" => stack.push(child),
+                    _ => {}
+                }
+            }
+        }
+        declarations
+    }
+}
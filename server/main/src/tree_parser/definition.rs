@@ -1,3 +1,4 @@
+use super::declaration::Declaration;
 use super::*;
 
 fn function_def_pattern(name: &str) -> String {
@@ -35,7 +36,295 @@ fn variable_def_pattern(name: &str) -> String {
     pattern
 }
 
+/// A named definition of a function, variable, parameter, struct or macro.
+pub struct Definition<'a> {
+    /// Identifier of the defined name
+    pub name: Node<'a>,
+    /// Node of the whole definition, such as `declaration`, `function_definition` or `preproc_def`
+    pub node: Node<'a>,
+    /// Declarator of this variable in declarations, like `init_declarator` or `array_declarator`
+    pub declarator: Option<Node<'a>>,
+}
+
+impl<'a> Definition<'a> {
+    pub(super) fn from_declaration(node: Node<'a>) -> Vec<Self> {
+        let declaration = match Declaration::new(node) {
+            Some(declaration) => declaration,
+            None => return vec![],
+        };
+        let mut definitions = declaration
+            .declarators
+            .into_iter()
+            .map(|(name, declarator)| Definition {
+                name,
+                node,
+                declarator: Some(declarator),
+            })
+            .collect::<Vec<_>>();
+        // `struct Light { ... } light;` defines both the struct and a variable
+        if let Some(name) = declaration
+            .type_node
+            .child_by_field_name("name")
+            .filter(|_| declaration.type_node.kind() == "struct_specifier")
+        {
+            definitions.push(Definition {
+                name,
+                node: declaration.type_node,
+                declarator: None,
+            });
+        }
+        definitions
+    }
+
+    pub(super) fn from_function(node: Node<'a>) -> Option<Self> {
+        let mut declarator = node.child_by_field_name("declarator")?;
+        while declarator.kind() != "function_declarator" {
+            declarator = declarator.child_by_field_name("declarator")?;
+        }
+        Some(Definition {
+            name: declarator.child_by_field_name("declarator")?,
+            node,
+            declarator: None,
+        })
+    }
+
+    /// Parameters of a function definition or a function declarator
+    pub(super) fn parameters(node: Node<'a>) -> Vec<Self> {
+        let declarator = match node.kind() {
+            "function_declarator" => Some(node),
+            _ => Self::from_function(node).and_then(|function| function.name.parent()),
+        };
+        let parameters = match declarator.and_then(|declarator| declarator.child_by_field_name("parameters")) {
+            Some(parameters) => parameters,
+            None => return vec![],
+        };
+        let mut cursor = parameters.walk();
+        parameters
+            .named_children(&mut cursor)
+            .filter(|parameter| parameter.kind() == "parameter_declaration")
+            .filter_map(|parameter| {
+                Some(Definition {
+                    name: Declaration::declarator_identifier(parameter.child_by_field_name("declarator")?)?,
+                    node: parameter,
+                    declarator: None,
+                })
+            })
+            .collect()
+    }
+
+    fn from_named(node: Node<'a>) -> Option<Self> {
+        Some(Definition {
+            name: node.child_by_field_name("name")?,
+            node,
+            declarator: None,
+        })
+    }
+
+    /// Declaration text of this definition, without function bodies and variable initializers
+    pub fn signature(&self, content: &str) -> String {
+        let text = |node: Node| node.utf8_text(content.as_bytes()).unwrap();
+        match self.node.kind() {
+            "declaration" => {
+                let declaration = Declaration::new(self.node).unwrap();
+                let mut signature = declaration.qualifiers.iter().map(|qualifier| text(*qualifier)).collect::<Vec<_>>();
+                signature.push(text(declaration.type_node));
+                let declarator = self.declarator.unwrap();
+                // Show values of constants
+                match declarator.child_by_field_name("declarator") {
+                    Some(inner) if declarator.kind() == "init_declarator" && !declaration.has_qualifier("const") => {
+                        signature.push(text(inner))
+                    }
+                    _ => signature.push(text(declarator)),
+                }
+                signature.join(" ") + ";"
+            }
+            "function_definition" => {
+                let declarator = self.node.child_by_field_name("declarator").unwrap();
+                content[self.node.start_byte()..declarator.end_byte()].to_owned()
+            }
+            "preproc_def" | "preproc_function_def" => {
+                let head_end = self
+                    .node
+                    .child_by_field_name("parameters")
+                    .map_or(self.name.end_byte(), |parameters| parameters.end_byte());
+                let mut signature = content[self.node.start_byte()..head_end].to_owned();
+                if let Some((value, _)) = self.node.child_by_field_name("value").map(|value| macro_value(text(value))) {
+                    signature.push(' ');
+                    signature += value;
+                }
+                signature
+            }
+            _ => text(self.node).to_owned(),
+        }
+    }
+
+    /// Comments right above this definition, or at the end of its line
+    pub fn documentation(&self, content: &str) -> Option<String> {
+        let text = |node: Node| node.utf8_text(content.as_bytes()).unwrap();
+        let mut comments = vec![];
+        let mut next_row = self.node.start_position().row;
+        let mut sibling = self.node.prev_sibling();
+        while let Some(comment) = sibling.filter(|node| node.kind() == "comment" && node.end_position().row + 1 >= next_row) {
+            sibling = comment.prev_sibling();
+            // Comments at the end of previous code line belong to that code
+            if sibling.is_some_and(|node| last_row(node) == comment.start_position().row) {
+                break;
+            }
+            comments.push(comment_text(text(comment)));
+            next_row = comment.start_position().row;
+        }
+        comments.reverse();
+
+        let trailing = match self.node.kind() {
+            "preproc_def" | "preproc_function_def" => self.node.child_by_field_name("value").and_then(|value| macro_value(text(value)).1),
+            _ => self
+                .node
+                .next_sibling()
+                .filter(|node| node.kind() == "comment" && node.start_position().row == last_row(self.node))
+                .map(text),
+        };
+        comments.extend(trailing.map(comment_text));
+
+        let documentation = comments.join("\n");
+        (!documentation.is_empty()).then_some(documentation)
+    }
+}
+
+/// Signature, documentation and range of a definition, detached from its syntax tree
+pub struct DefinitionInfo {
+    pub signature: String,
+    pub documentation: Option<String>,
+    /// Range of the defined name
+    pub range: Range,
+}
+
+impl Definition<'_> {
+    fn info(&self, content: &str, line_mapping: &[usize]) -> DefinitionInfo {
+        DefinitionInfo {
+            signature: self.signature(content),
+            documentation: self.documentation(content),
+            range: self.name.to_range(content, line_mapping),
+        }
+    }
+}
+
+/// Last row of this node. Preprocessor directives end at the start of next line.
+pub(super) fn last_row(node: Node) -> usize {
+    let end_position = node.end_position();
+    if end_position.column == 0 && end_position.row > node.start_position().row {
+        end_position.row - 1
+    } else {
+        end_position.row
+    }
+}
+
+/// Split a macro value into its content and the comment at the end of line
+fn macro_value(value: &str) -> (&str, Option<&str>) {
+    match value.find("//").into_iter().chain(value.find("/*")).min() {
+        Some(index) => (value[..index].trim_end(), Some(&value[index..])),
+        None => (value.trim_end(), None),
+    }
+}
+
+/// Remove comment markers and leading `*` of each line
+fn comment_text(comment: &str) -> String {
+    let comment = match comment.strip_prefix("/*") {
+        Some(comment) => comment.strip_suffix("*/").unwrap_or(comment),
+        None => comment.trim_start_matches('/'),
+    };
+    comment
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').unwrap_or(line).trim()
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 impl TreeParser {
+    /// Functions, global variables, structs and macros in this file, including those wrapped by preprocessor conditions
+    pub fn global_definitions(tree: &Tree) -> Vec<Definition<'_>> {
+        let root_node = tree.root_node();
+        let mut cursor = root_node.walk();
+        let mut definitions = vec![];
+        let mut stack = vec![root_node];
+        while let Some(node) = stack.pop() {
+            for child in node.named_children(&mut cursor) {
+                match child.kind() {
+                    "declaration" => definitions.extend(Definition::from_declaration(child)),
+                    "function_definition" => definitions.extend(Definition::from_function(child)),
+                    "struct_specifier" | "preproc_def" | "preproc_function_def" => definitions.extend(Definition::from_named(child)),
+                    "preproc_if" | "preproc_ifdef" | "preproc_else" | "preproc_elif" | "preproc_elifdef" => stack.push(child),
+                    _ => {}
+                }
+            }
+        }
+        definitions.sort_unstable_by_key(|definition| definition.name.start_byte());
+        definitions
+    }
+
+    /// Nearest local variable or parameter definition visible from this identifier
+    pub(super) fn local_definition<'a>(identifier: Node<'a>, content: &str) -> Option<Definition<'a>> {
+        Self::scope_definition(identifier, identifier.utf8_text(content.as_bytes()).unwrap(), content)
+    }
+
+    /// Nearest local variable or parameter with this name visible from this node
+    pub(super) fn scope_definition<'a>(node: Node<'a>, name: &str, content: &str) -> Option<Definition<'a>> {
+        let is_name = |identifier: &Node| identifier.utf8_text(content.as_bytes()).unwrap() == name;
+
+        let mut current = node;
+        while let Some(parent) = current.parent() {
+            let mut cursor = parent.walk();
+            let definition = match parent.kind() {
+                "compound_statement" | "for_statement" => parent
+                    .named_children(&mut cursor)
+                    .filter(|child| child.start_byte() <= node.start_byte())
+                    .flat_map(Definition::from_declaration)
+                    .filter(|definition| is_name(&definition.name))
+                    .last(),
+                "function_definition" => {
+                    // Function bodies are the outermost local scope
+                    return Definition::parameters(parent)
+                        .into_iter()
+                        .find(|definition| is_name(&definition.name));
+                }
+                _ => None,
+            };
+            if definition.is_some() {
+                return definition;
+            }
+            current = parent;
+        }
+        None
+    }
+
+    /// Name and range of the identifier at this position, with its local definition if it is a local variable or parameter
+    pub fn identifier_definition(
+        position: Position, tree: &Tree, content: &str, line_mapping: &[usize],
+    ) -> Option<(String, Range, Option<DefinitionInfo>)> {
+        let node = Self::current_node_fetch(position, tree, content, line_mapping)?;
+        if !matches!(node.kind(), "identifier" | "type_identifier") {
+            return None;
+        }
+        let local_definition = Self::local_definition(node, content).map(|definition| definition.info(content, line_mapping));
+        Some((
+            node.utf8_text(content.as_bytes()).unwrap().to_owned(),
+            node.to_range(content, line_mapping),
+            local_definition,
+        ))
+    }
+
+    /// Global definitions with this name in this file
+    pub fn named_definitions(name: &str, tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<DefinitionInfo> {
+        Self::global_definitions(tree)
+            .into_iter()
+            .filter(|definition| definition.name.utf8_text(content.as_bytes()).unwrap() == name)
+            .map(|definition| definition.info(content, line_mapping))
+            .collect()
+    }
+
     fn tree_climbing_search(content: &str, url: &Url, start_node: Node, line_mapping: &[usize]) -> Vec<Location> {
         let mut locations = vec![];
 
@@ -0,0 +1,137 @@
+use super::definition::last_row;
+use super::*;
+
+/// Directive groups folded together when they are on consecutive lines
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DirectiveGroup {
+    Include,
+    Define,
+}
+
+fn folding_range(start_line: usize, end_line: usize, kind: Option<FoldingRangeKind>) -> FoldingRange {
+    FoldingRange {
+        start_line: start_line as u32,
+        start_character: None,
+        end_line: end_line as u32,
+        end_character: None,
+        kind,
+        collapsed_text: None,
+    }
+}
+
+impl TreeParser {
+    /// Folding ranges of code blocks, comments, preprocessor conditions and groups of includes and defines
+    pub fn folding_ranges(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<FoldingRange> {
+        let row_count = line_mapping.len() - 1;
+        let mut ranges = vec![];
+        let mut in_comment = vec![false; row_count];
+        let mut line_comments = vec![false; row_count];
+
+        for node in named_descendants(tree.root_node()) {
+            let start_row = node.start_position().row;
+            let end_row = last_row(node);
+            match node.kind() {
+                // The closing brace stays visible
+                "compound_statement" | "field_declaration_list" | "initializer_list" if end_row > start_row => {
+                    let closed = node
+                        .child(node.child_count().saturating_sub(1))
+                        .is_some_and(|last| last.kind() == "}" && !last.is_missing() && last.start_position().row > start_row + 1);
+                    if closed {
+                        ranges.push(folding_range(start_row, node.end_position().row - 1, None));
+                    }
+                }
+                "comment" if end_row > start_row => {
+                    ranges.push(folding_range(start_row, end_row, Some(FoldingRangeKind::Comment)));
+                    in_comment[start_row + 1..=end_row].fill(true);
+                }
+                "comment" => line_comments[start_row] = node.utf8_text(content.as_bytes()).unwrap().starts_with("//"),
+                _ => {}
+            }
+        }
+
+        // Consecutive lines of line comments
+        let mut comment_start = None;
+        for row in 0..=row_count {
+            let is_comment = row < row_count && line_comments[row] && content[line_mapping[row]..].trim_start().starts_with("//");
+            match (is_comment, comment_start) {
+                (true, None) => comment_start = Some(row),
+                (false, Some(start)) => {
+                    if row - 1 > start {
+                        ranges.push(folding_range(start, row - 1, Some(FoldingRangeKind::Comment)));
+                    }
+                    comment_start = None;
+                }
+                _ => {}
+            }
+        }
+
+        let mut conditions: Vec<usize> = vec![];
+        let mut group: Option<(DirectiveGroup, usize)> = None;
+        let mut continued = false;
+        for row in 0..=row_count {
+            let line = if row < row_count && !in_comment[row] {
+                content[line_mapping[row]..line_mapping[row + 1] - 1].trim_end_matches('\r')
+            } else {
+                ""
+            };
+            // Lines continuing a directive belong to its group
+            if continued {
+                continued = line.ends_with('\\');
+                continue;
+            }
+            continued = line.ends_with('\\');
+
+            let directive = line
+                .trim_start()
+                .strip_prefix('#')
+                .map(|directive| {
+                    let directive = directive.trim_start();
+                    &directive[..directive
+                        .find(|char: char| !char.is_ascii_alphanumeric() && char != '_')
+                        .unwrap_or(directive.len())]
+                })
+                .unwrap_or_default();
+            match directive {
+                "if" | "ifdef" | "ifndef" => conditions.push(row),
+                "elif" | "elifdef" | "elifndef" | "else" => {
+                    if let Some(start) = conditions.last_mut() {
+                        if row - 1 > *start {
+                            ranges.push(folding_range(*start, row - 1, None));
+                        }
+                        *start = row;
+                    }
+                }
+                "endif" => {
+                    if let Some(start) = conditions.pop() {
+                        if row - 1 > start {
+                            ranges.push(folding_range(start, row - 1, None));
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            let current = match directive {
+                "include" | "moj_import" => Some(DirectiveGroup::Include),
+                "define" => Some(DirectiveGroup::Define),
+                _ => None,
+            };
+            match group {
+                Some((kind, _)) if current == Some(kind) => {}
+                _ => {
+                    if let Some((kind, start)) = group {
+                        let end = row - 1;
+                        if end > start {
+                            let kind = (kind == DirectiveGroup::Include).then_some(FoldingRangeKind::Imports);
+                            ranges.push(folding_range(start, end, kind));
+                        }
+                    }
+                    group = current.map(|kind| (kind, row));
+                }
+            }
+        }
+
+        ranges.sort_unstable_by_key(|range| (range.start_line, std::cmp::Reverse(range.end_line)));
+        ranges
+    }
+}
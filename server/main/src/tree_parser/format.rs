@@ -0,0 +1,241 @@
+use std::ops::Range;
+
+use serde::Deserialize;
+
+use crate::configuration::or_default;
+
+use super::definition::last_row;
+use super::*;
+
+/// Where opening braces of functions, structs and control statements are placed
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BraceStyle {
+    /// Keep braces where they are
+    Preserve,
+    /// At the end of the line of the statement
+    #[default]
+    SameLine,
+    /// At the start of the next line
+    NextLine,
+}
+
+/// Code style options of formatting, indentation comes from editor options
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FormatStyle {
+    #[serde(deserialize_with = "or_default")]
+    pub brace_style: BraceStyle,
+    pub space_around_operators: bool,
+}
+
+impl Default for FormatStyle {
+    fn default() -> Self {
+        FormatStyle {
+            brace_style: BraceStyle::default(),
+            space_around_operators: true,
+        }
+    }
+}
+
+/// Nodes whose children are statements, or declarations at top level
+const STATEMENT_PARENTS: &[&str] = &[
+    "translation_unit",
+    "compound_statement",
+    "field_declaration_list",
+    "preproc_if",
+    "preproc_ifdef",
+    "preproc_else",
+    "preproc_elif",
+    "preproc_elifdef",
+];
+
+/// Tokens printed as a whole, even if they have children
+const ATOMIC_TOKENS: &[&str] = &["comment", "string_literal", "char_literal", "system_lib_string"];
+
+/// Whether this child of a case statement is a statement, instead of the case label
+fn is_case_body(case_statement: Node, child: Node) -> bool {
+    child.is_named() && case_statement.child_by_field_name("value") != Some(child)
+}
+
+/// Indent level of a line starting with this token
+fn indent_level(token: Node) -> usize {
+    let row = token.start_position().row;
+    let mut level = 0;
+    let mut continuation = None;
+    let mut child = token;
+    while let Some(parent) = child.parent() {
+        let is_brace = !child.is_named() && matches!(child.kind(), "{" | "}");
+        match parent.kind() {
+            "compound_statement" | "field_declaration_list" | "initializer_list" if !is_brace => level += 1,
+            "case_statement" if is_case_body(parent, child) => level += 1,
+            _ => {}
+        }
+        // Lines inside a statement starting on previous lines are continuations
+        if continuation.is_none()
+            && (STATEMENT_PARENTS.contains(&parent.kind()) || parent.kind() == "case_statement" && is_case_body(parent, child))
+        {
+            continuation = Some(child.start_position().row < row);
+        }
+        child = parent;
+    }
+    let closing = !token.is_named() && matches!(token.kind(), "{" | "}" | ")" | "]" | "else");
+    level + (continuation == Some(true) && !closing) as usize
+}
+
+/// Whether this token is an opening brace of a function, struct or control statement body
+fn is_block_brace(token: Node) -> bool {
+    token.kind() == "{"
+        && !token.is_named()
+        && token
+            .parent()
+            .filter(|block| matches!(block.kind(), "compound_statement" | "field_declaration_list"))
+            .and_then(|block| block.parent())
+            .is_some_and(|owner| !STATEMENT_PARENTS.contains(&owner.kind()) && owner.kind() != "case_statement")
+}
+
+/// Whether this token is an operator with spaces around it, like binary operators and assignments
+fn is_spaced_operator(token: Node) -> bool {
+    !token.is_named()
+        && token.parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                "binary_expression" | "assignment_expression" | "conditional_expression" | "init_declarator" | "qualifier"
+            )
+        })
+}
+
+/// Whitespace between two tokens on the same line
+fn spacing<'a>(previous: Node, current: Node, content: &'a str, style: &FormatStyle) -> &'a str {
+    let operator_space = if style.space_around_operators { " " } else { "" };
+    let text = |node: Node| node.utf8_text(content.as_bytes()).unwrap();
+    // Trailing comments keep their alignment, like OptiFine option comments
+    if current.kind() == "comment" {
+        return &content[previous.end_byte()..current.start_byte()];
+    }
+    if previous.kind() == "comment" {
+        return " ";
+    }
+    let parent_kind = |node: Node| node.parent().map_or("", |parent| parent.kind());
+    match (text(previous), text(current)) {
+        ("(" | "[" | ".", _) | (_, ";" | "," | ")" | "]" | ".") | ("{", "}") => "",
+        _ if is_spaced_operator(previous) || is_spaced_operator(current) => operator_space,
+        // Prefix operators stick to their operands
+        _ if !previous.is_named()
+            && matches!(parent_kind(previous), "unary_expression" | "update_expression")
+            && previous.prev_sibling().is_none() =>
+        {
+            ""
+        }
+        _ if !current.is_named() && parent_kind(current) == "update_expression" && current.next_sibling().is_none() => "",
+        (_, "(") if previous.is_named() || matches!(text(previous), "]" | "layout") => "",
+        (_, "[") => "",
+        (_, ":") if parent_kind(current) == "case_statement" => "",
+        _ => " ",
+    }
+}
+
+/// Leaf tokens of this tree in document order, with comments and strings as single tokens
+fn tokens(tree: &Tree) -> Vec<Node<'_>> {
+    let mut tokens = vec![];
+    let mut cursor = tree.walk();
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        if node.child_count() == 0 || ATOMIC_TOKENS.contains(&node.kind()) {
+            tokens.push(node);
+        } else {
+            let children = node.children(&mut cursor).collect::<Vec<_>>();
+            stack.extend(children.into_iter().rev());
+        }
+    }
+    tokens
+}
+
+impl TreeParser {
+    /// Format lines in `rows` of this file, returning their new text joined by the line breaks of the file.
+    ///
+    /// Preprocessor directives, multi-line comments and lines with syntax errors are kept untouched.
+    pub fn format(
+        tree: &Tree, content: &str, line_mapping: &[usize], style: &FormatStyle, indent_unit: &str, rows: Range<usize>,
+    ) -> String {
+        let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+        let line_text = |row: usize| content[line_mapping[row]..line_mapping[row + 1] - 1].trim_end_matches('\r');
+
+        // The line mapping ends with the end of content
+        let row_count = line_mapping.len() - 1;
+        let mut untouched = (0..row_count).map(|row| !rows.contains(&row)).collect::<Vec<_>>();
+        let mut in_directive = false;
+        for (row, untouched) in untouched.iter_mut().enumerate() {
+            let line = line_text(row);
+            // Directives continue to next line with `\` at the end
+            if in_directive || line.trim_start().starts_with('#') {
+                *untouched = true;
+                in_directive = line.ends_with('\\');
+            }
+        }
+        let mut stack = vec![tree.root_node()];
+        let mut cursor = tree.walk();
+        while let Some(node) = stack.pop() {
+            if node.is_error() || node.is_missing() {
+                untouched[node.start_position().row..=last_row(node)].fill(true);
+            } else if node.has_error() {
+                stack.extend(node.children(&mut cursor));
+            }
+        }
+        let tokens = tokens(tree);
+        for token in &tokens {
+            if token.start_position().row != last_row(*token) {
+                untouched[token.start_position().row..=last_row(*token)].fill(true);
+            }
+        }
+
+        let mut lines = vec![vec![]; row_count];
+        for token in tokens {
+            let row = token.start_position().row;
+            if !untouched[row] && token.start_byte() < token.end_byte() {
+                lines[row].push(token);
+            }
+        }
+        let mut joined = vec![false; row_count];
+        if style.brace_style == BraceStyle::SameLine {
+            for row in 1..row_count {
+                let previous_end = lines[row - 1].last().filter(|token| token.kind() != "comment");
+                if previous_end.is_some() && lines[row].first().is_some_and(|token| is_block_brace(*token)) {
+                    let moved = std::mem::take(&mut lines[row]);
+                    lines[row - 1].extend(moved);
+                    joined[row] = true;
+                }
+            }
+        }
+
+        let mut output = vec![];
+        for row in rows {
+            if joined[row] {
+                continue;
+            }
+            if untouched[row] {
+                output.push(line_text(row).to_owned());
+                continue;
+            }
+            let tokens = &lines[row];
+            if tokens.is_empty() {
+                output.push(String::new());
+                continue;
+            }
+            let mut line = String::new();
+            for (index, token) in tokens.iter().enumerate() {
+                if index == 0 {
+                    line += &indent_unit.repeat(indent_level(*token));
+                } else if style.brace_style == BraceStyle::NextLine && is_block_brace(*token) {
+                    output.push(std::mem::take(&mut line));
+                    line += &indent_unit.repeat(indent_level(*token));
+                } else {
+                    line += spacing(tokens[index - 1], *token, content, style);
+                }
+                line += token.utf8_text(content.as_bytes()).unwrap();
+            }
+            output.push(line);
+        }
+        output.join(newline)
+    }
+}
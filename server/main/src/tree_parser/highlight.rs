@@ -0,0 +1,155 @@
+use hashbrown::HashMap;
+
+use crate::builtin::{builtins_named, BuiltinKind};
+use crate::file::byte_index;
+
+use super::*;
+
+/// Whether each parameter of this declarator is `out` or `inout`
+fn declarator_out_parameters(declarator: Node) -> Vec<bool> {
+    let parameters = match declarator.child_by_field_name("parameters") {
+        Some(parameters) => parameters,
+        None => return vec![],
+    };
+    let mut cursor = parameters.walk();
+    let parameters = parameters
+        .named_children(&mut cursor)
+        .filter(|parameter| parameter.kind() == "parameter_declaration")
+        .collect::<Vec<_>>();
+    parameters
+        .into_iter()
+        .map(|parameter| {
+            let mut cursor = parameter.walk();
+            let is_out = parameter.children(&mut cursor).any(|child| matches!(child.kind(), "out" | "inout"));
+            is_out
+        })
+        .collect()
+}
+
+/// Whether each parameter of this built-in signature, like `genType modf(genType x, out genType i)`, is `out` or `inout`
+fn signature_out_parameters(signature: &str) -> Vec<bool> {
+    let parameters = signature
+        .split_once('(')
+        .and_then(|(_, parameters)| parameters.rsplit_once(')'))
+        .map_or("", |(parameters, _)| parameters);
+    parameters
+        .split(',')
+        .map(|parameter| {
+            let parameter = parameter.trim_start();
+            parameter.starts_with("out ") || parameter.starts_with("inout ")
+        })
+        .collect()
+}
+
+/// Functions with `out` or `inout` parameters, collected from files visible to the highlighted file
+#[derive(Default)]
+pub struct OutParameters(HashMap<String, Vec<Vec<bool>>>);
+
+impl OutParameters {
+    /// Add functions defined or declared by prototypes in this file
+    pub fn extend(&mut self, tree: &Tree, content: &str) {
+        for declarator in named_descendants(tree.root_node()) {
+            if declarator.kind() != "function_declarator" {
+                continue;
+            }
+            let parameters = declarator_out_parameters(declarator);
+            if !parameters.contains(&true) {
+                continue;
+            }
+            if let Some(name) = declarator.child_by_field_name("declarator") {
+                let name = name.utf8_text(content.as_bytes()).unwrap().to_owned();
+                let overloads = self.0.entry(name).or_default();
+                if !overloads.contains(&parameters) {
+                    overloads.push(parameters);
+                }
+            }
+        }
+    }
+
+    /// Whether the argument at `index` of a call with `count` arguments may be written by the function
+    fn is_out(&self, name: &str, count: usize, index: usize) -> bool {
+        let builtin_overloads = builtins_named(name)
+            .iter()
+            .filter(|builtin| builtin.kind == BuiltinKind::Function)
+            .flat_map(|builtin| builtin.signatures.iter().map(|signature| signature_out_parameters(signature)));
+        self.0
+            .get(name)
+            .into_iter()
+            .flatten()
+            .cloned()
+            .chain(builtin_overloads)
+            .any(|parameters| parameters.len() == count && parameters[index])
+    }
+}
+
+impl TreeParser {
+    /// Whether this occurrence of a symbol is read or written
+    fn highlight_kind(node: Node, content: &str, out_parameters: &OutParameters) -> DocumentHighlightKind {
+        let mut child = node;
+        while let Some(parent) = child.parent() {
+            match parent.kind() {
+                // Writing to a field or an element writes the whole variable
+                "field_expression" | "parenthesized_expression" => child = parent,
+                "subscript_expression" if parent.child_by_field_name("argument") == Some(child) => child = parent,
+                "assignment_expression" if parent.child_by_field_name("left") == Some(child) => return DocumentHighlightKind::WRITE,
+                "update_expression" => return DocumentHighlightKind::WRITE,
+                "argument_list" => {
+                    let function = parent
+                        .parent()
+                        .and_then(|call| call.child_by_field_name("function"))
+                        .map_or("", |function| function.utf8_text(content.as_bytes()).unwrap());
+                    let mut cursor = parent.walk();
+                    let arguments = parent
+                        .named_children(&mut cursor)
+                        .filter(|argument| argument.kind() != "comment")
+                        .collect::<Vec<_>>();
+                    let index = arguments.iter().position(|argument| *argument == child).unwrap_or(0);
+                    return match out_parameters.is_out(function, arguments.len(), index) {
+                        true => DocumentHighlightKind::WRITE,
+                        false => DocumentHighlightKind::READ,
+                    };
+                }
+                _ => return DocumentHighlightKind::READ,
+            }
+        }
+        DocumentHighlightKind::READ
+    }
+
+    /// Occurrences of this symbol in this file, marking assignments, increments and `out` arguments as writes.
+    ///
+    /// Definitions with initial values and parameters are writes, other definitions and uses in macro values are text.
+    pub fn document_highlights(
+        symbol: &Symbol, tree: &Tree, content: &str, line_mapping: &[usize], types: &TypeTable, out_parameters: &OutParameters,
+    ) -> Vec<DocumentHighlight> {
+        Self::symbol_references(symbol, tree, content, line_mapping, types)
+            .into_iter()
+            .map(|reference| {
+                let start = byte_index(content, reference.range.start, line_mapping).0;
+                let end = byte_index(content, reference.range.end, line_mapping).0;
+                let node = tree
+                    .root_node()
+                    .named_descendant_for_byte_range(start, end)
+                    .filter(|node| node.start_byte() == start && node.end_byte() == end);
+                let kind = match node {
+                    Some(node) if reference.is_definition => {
+                        let initialized = node.parent().is_some_and(|parent| match parent.kind() {
+                            "init_declarator" | "parameter_declaration" => true,
+                            "array_declarator" => parent.parent().is_some_and(|declarator| declarator.kind() == "init_declarator"),
+                            _ => false,
+                        });
+                        match initialized {
+                            true => DocumentHighlightKind::WRITE,
+                            false => DocumentHighlightKind::TEXT,
+                        }
+                    }
+                    Some(node) => Self::highlight_kind(node, content, out_parameters),
+                    None => DocumentHighlightKind::TEXT,
+                };
+                DocumentHighlight {
+                    range: reference.range,
+                    kind: Some(kind),
+                }
+            })
+            .collect()
+    }
+}
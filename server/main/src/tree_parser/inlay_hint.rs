@@ -0,0 +1,294 @@
+use hashbrown::HashMap;
+
+use super::declaration::Declaration;
+use super::definition::Definition;
+use super::*;
+
+/// Parameter types counted when deciding whether a call gets parameter name hints
+const FLOAT_TYPES: &[&str] = &["float", "vec2", "vec3", "vec4", "double", "dvec2", "dvec3", "dvec4"];
+
+/// Calls of functions with at least this many float parameters get parameter name hints
+const MIN_FLOAT_PARAMETERS: usize = 2;
+
+/// Whether this macro value is a number or boolean literal
+fn is_literal(value: &str) -> bool {
+    let number = value.trim_end_matches(['f', 'F', 'u', 'U']);
+    matches!(value, "true" | "false")
+        || number.parse::<f64>().is_ok()
+        || number
+            .strip_prefix("0x")
+            .or_else(|| number.strip_prefix("0X"))
+            .is_some_and(|hex| !hex.is_empty() && hex.chars().all(|char| char.is_ascii_hexdigit()))
+}
+
+/// Number in a literal, and whether it is a float
+fn literal_number(literal: &str) -> Option<(f64, bool)> {
+    if let Some(hex) = literal.strip_prefix("0x").or_else(|| literal.strip_prefix("0X")) {
+        let hex = hex.trim_end_matches(['u', 'U']);
+        return i64::from_str_radix(hex, 16).ok().map(|number| (number as f64, false));
+    }
+    let is_float = literal.contains(['.', 'e', 'E', 'f', 'F']);
+    let number = literal.trim_end_matches(['f', 'F', 'u', 'U']).parse::<f64>().ok()?;
+    Some((number, is_float))
+}
+
+/// Value of a constant arithmetic expression of number literals and option macros, and whether it is a float
+fn constant_value(node: Node, content: &str, context: &HintContext) -> Option<(f64, bool)> {
+    let text = |node: Node| node.utf8_text(content.as_bytes()).unwrap();
+    match node.kind() {
+        "number_literal" => literal_number(text(node)),
+        "identifier" => literal_number(context.macro_value(text(node))?),
+        "parenthesized_expression" => constant_value(node.named_child(0)?, content, context),
+        "unary_expression" => {
+            let (value, is_float) = constant_value(node.child_by_field_name("argument")?, content, context)?;
+            match text(node.child_by_field_name("operator")?) {
+                "-" => Some((-value, is_float)),
+                "+" => Some((value, is_float)),
+                _ => None,
+            }
+        }
+        "binary_expression" => {
+            let (left, left_float) = constant_value(node.child_by_field_name("left")?, content, context)?;
+            let (right, right_float) = constant_value(node.child_by_field_name("right")?, content, context)?;
+            let is_float = left_float || right_float;
+            let value = match text(node.child_by_field_name("operator")?) {
+                "+" => left + right,
+                "-" => left - right,
+                "*" => left * right,
+                "/" if right == 0.0 => return None,
+                "/" if is_float => left / right,
+                "/" => (left / right).trunc(),
+                "%" if !is_float && right != 0.0 => left % right,
+                _ => return None,
+            };
+            Some((value, is_float))
+        }
+        _ => None,
+    }
+}
+
+fn inlay_hint(position: Position, label: String, kind: Option<InlayHintKind>) -> InlayHint {
+    let is_parameter = kind == Some(InlayHintKind::PARAMETER);
+    InlayHint {
+        position,
+        label: InlayHintLabel::String(label),
+        kind,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(!is_parameter),
+        padding_right: Some(is_parameter),
+        data: None,
+    }
+}
+
+/// Values of object-like macros and parameters of functions, collected from files visible to the hinted file
+pub struct HintContext {
+    /// Macro values, `None` if definitions in different places disagree
+    macros: HashMap<String, Option<String>>,
+    /// Overloads of functions, as lists of parameter types and names
+    functions: HashMap<String, Vec<Vec<(String, String)>>>,
+}
+
+impl HintContext {
+    /// Context starting with these `#define` lines, like macros provided by OptiFine
+    pub fn new(predefined_macros: &str) -> Self {
+        let macros = predefined_macros
+            .lines()
+            .filter_map(|line| line.strip_prefix("#define ")?.split_once(' '))
+            .map(|(name, value)| (name.to_owned(), Some(value.trim().to_owned())))
+            .collect();
+        HintContext {
+            macros,
+            functions: HashMap::new(),
+        }
+    }
+
+    /// Add macros and functions defined in this file
+    pub fn extend(&mut self, tree: &Tree, content: &str) {
+        let text = |node: Node| node.utf8_text(content.as_bytes()).unwrap();
+        for definition in TreeParser::global_definitions(tree) {
+            let name = text(definition.name);
+            match definition.node.kind() {
+                "preproc_def" => {
+                    // OptiFine options list their allowed values in comments
+                    let value = definition.node.child_by_field_name("value").map_or("", |value| {
+                        let value = text(value);
+                        value[..value.find("//").or_else(|| value.find("/*")).unwrap_or(value.len())].trim()
+                    });
+                    match self.macros.get_mut(name) {
+                        Some(existing) if existing.as_deref() != Some(value) => *existing = None,
+                        Some(_) => {}
+                        None => {
+                            self.macros.insert(name.to_owned(), Some(value.to_owned()));
+                        }
+                    }
+                }
+                "function_definition" => {
+                    let parameters = Definition::parameters(definition.node)
+                        .into_iter()
+                        .map(|parameter| {
+                            let type_name = parameter.node.child_by_field_name("type").map_or("", text);
+                            (type_name.to_owned(), text(parameter.name).to_owned())
+                        })
+                        .collect::<Vec<_>>();
+                    let overloads = self.functions.entry(name.to_owned()).or_default();
+                    if !overloads.contains(&parameters) {
+                        overloads.push(parameters);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Literal value of a macro, following macros defined as other macros
+    fn macro_value<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        let mut value = name;
+        for _ in 0..10 {
+            value = self.macros.get(value)?.as_deref()?;
+            if is_literal(value) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl TreeParser {
+    /// Value of a constant initialized by an expression of literals and option macros, at the end of its initializer
+    fn constant_hint(declarator: Node, content: &str, line_mapping: &[usize], context: &HintContext) -> Option<InlayHint> {
+        let value = declarator.child_by_field_name("value")?;
+        // Literals show their values, and option macros get hints of their own
+        let is_plain = match value.kind() {
+            "number_literal" | "identifier" => true,
+            "unary_expression" => value
+                .child_by_field_name("argument")
+                .is_some_and(|argument| argument.kind() == "number_literal"),
+            _ => false,
+        };
+        if is_plain {
+            return None;
+        }
+        let (number, is_float) = constant_value(value, content, context)?;
+        let label = if !is_float {
+            format!("= {}", number as i64)
+        } else if number.fract() == 0.0 && number.abs() < 1e15 {
+            format!("= {:.1}", number)
+        } else {
+            format!("= {}", number)
+        };
+        Some(inlay_hint(value.to_range(content, line_mapping).end, label, None))
+    }
+
+    /// Inlay hints in `rows` of this file: values of option macros and constants, parameter names of calls and sizes of constant arrays
+    pub fn inlay_hints(
+        tree: &Tree, content: &str, line_mapping: &[usize], context: &HintContext, rows: std::ops::Range<usize>,
+    ) -> Vec<InlayHint> {
+        let text = |node: Node| node.utf8_text(content.as_bytes()).unwrap();
+        let mut hints = vec![];
+        for node in named_descendants(tree.root_node()) {
+            if !rows.contains(&node.start_position().row) {
+                continue;
+            }
+            match node.kind() {
+                "identifier" => {
+                    let is_macro_use = node.parent().is_some_and(|parent| {
+                        !matches!(
+                            parent.kind(),
+                            "preproc_def" | "preproc_function_def" | "preproc_ifdef" | "preproc_defined" | "preproc_params"
+                        )
+                    });
+                    if !is_macro_use || Self::local_definition(node, content).is_some() {
+                        continue;
+                    }
+                    if let Some(value) = context.macro_value(text(node)) {
+                        let position = node.to_range(content, line_mapping).end;
+                        hints.push(inlay_hint(position, "= ".to_owned() + value, None));
+                    }
+                }
+                "call_expression" => {
+                    let function = match node.child_by_field_name("function") {
+                        Some(function) if function.kind() == "identifier" => function,
+                        _ => continue,
+                    };
+                    let arguments = match node.child_by_field_name("arguments") {
+                        Some(arguments) => {
+                            let mut cursor = arguments.walk();
+                            let arguments = arguments.named_children(&mut cursor).collect::<Vec<_>>();
+                            arguments
+                                .into_iter()
+                                .filter(|argument| argument.kind() != "comment")
+                                .collect::<Vec<_>>()
+                        }
+                        None => continue,
+                    };
+                    let overloads = match context.functions.get(text(function)) {
+                        Some(overloads) if Self::local_definition(function, content).is_none() => overloads,
+                        _ => continue,
+                    };
+                    let parameters = match overloads.iter().find(|parameters| parameters.len() == arguments.len()) {
+                        Some(parameters) => parameters,
+                        None => continue,
+                    };
+                    let float_count = parameters
+                        .iter()
+                        .filter(|(type_name, _)| FLOAT_TYPES.contains(&type_name.as_str()))
+                        .count();
+                    if float_count < MIN_FLOAT_PARAMETERS {
+                        continue;
+                    }
+                    for (argument, (_, name)) in arguments.into_iter().zip(parameters) {
+                        // Arguments named like their parameters explain themselves
+                        let argument_name = match argument.kind() {
+                            "field_expression" => argument.child_by_field_name("field").map_or("", text),
+                            _ => text(argument),
+                        };
+                        if argument_name != name {
+                            let position = argument.to_range(content, line_mapping).start;
+                            hints.push(inlay_hint(position, name.clone() + ":", Some(InlayHintKind::PARAMETER)));
+                        }
+                    }
+                }
+                // Sizes of unsized constant arrays and values of constants come from their initializers
+                "declaration" => {
+                    let declaration = match Declaration::new(node) {
+                        Some(declaration) if declaration.has_qualifier("const") => declaration,
+                        _ => continue,
+                    };
+                    for (_, declarator) in &declaration.declarators {
+                        let array = match declarator.child_by_field_name("declarator") {
+                            Some(array) if declarator.kind() == "init_declarator" && array.kind() == "array_declarator" => array,
+                            Some(_) if declarator.kind() == "init_declarator" => {
+                                if let Some(hint) = Self::constant_hint(*declarator, content, line_mapping, context) {
+                                    hints.push(hint);
+                                }
+                                continue;
+                            }
+                            _ => continue,
+                        };
+                        if array.child_by_field_name("size").is_some() {
+                            continue;
+                        }
+                        let elements = match declarator.child_by_field_name("value") {
+                            Some(value) if value.kind() == "call_expression" => value.child_by_field_name("arguments"),
+                            Some(value) if value.kind() == "initializer_list" => Some(value),
+                            _ => None,
+                        };
+                        if let Some(elements) = elements {
+                            let mut cursor = elements.walk();
+                            let size = elements
+                                .named_children(&mut cursor)
+                                .filter(|element| element.kind() != "comment")
+                                .count();
+                            let label = format!(": {}[{}]", text(declaration.type_node), size);
+                            let position = array.to_range(content, line_mapping).end;
+                            hints.push(inlay_hint(position, label, Some(InlayHintKind::TYPE)));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        hints
+    }
+}
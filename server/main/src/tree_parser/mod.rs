@@ -5,8 +5,32 @@ use url::Url;
 
 use crate::file::byte_index;
 
+pub use call::FunctionCalls;
+pub use definition::DefinitionInfo;
+pub use format::FormatStyle;
+pub use highlight::OutParameters;
+pub use inlay_hint::HintContext;
+pub use reference::{Reference, Symbol, TypeTable};
+pub use render_target::FragmentOutput;
+pub use semantic_token::{TokenKinds, TOKEN_MODIFIERS, TOKEN_TYPES};
+pub use signature::signature_information;
+
+mod call;
+mod code_action;
+mod completion;
+mod const_option;
+mod custom_uniform;
+mod declaration;
 mod definition;
+mod folding;
+mod format;
+mod highlight;
+mod inlay_hint;
 mod reference;
+mod render_target;
+mod selection_range;
+mod semantic_token;
+mod signature;
 mod simple_lint;
 mod symbols;
 
@@ -49,19 +73,45 @@ impl ToLspTypes for Node<'_> {
     }
 }
 
+/// All named nodes in this tree in document order
+fn named_descendants(node: Node) -> Vec<Node> {
+    let mut nodes = vec![];
+    let mut cursor = node.walk();
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        nodes.push(node);
+        let children = node.named_children(&mut cursor).collect::<Vec<_>>();
+        stack.extend(children.into_iter().rev());
+    }
+    nodes
+}
+
 pub struct TreeParser;
 
 impl TreeParser {
     fn current_node_fetch<'a>(position: Position, tree: &'a Tree, content: &str, line_mapping: &[usize]) -> Option<Node<'a>> {
         let position_offset = byte_index(content, position, line_mapping).0;
 
-        let (start, end) = match content.as_bytes()[position_offset] {
-            b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'_' => (position_offset, position_offset + 1),
-            _ => (position_offset - 1, position_offset),
+        // Cursors may be at the end of file, after the last byte
+        let (start, end) = match content.as_bytes().get(position_offset) {
+            Some(b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'_') => (position_offset, position_offset + 1),
+            _ => {
+                let start = position_offset.checked_sub(1).filter(|start| *start < content.len())?;
+                (start, start + 1)
+            }
         };
         tree.root_node().named_descendant_for_byte_range(start, end)
     }
 
+    /// Convert a byte offset in content to a document position
+    fn offset_position(content: &str, line_mapping: &[usize], offset: usize) -> Position {
+        let line = line_mapping.partition_point(|line_start| *line_start <= offset).saturating_sub(1);
+        Position {
+            line: line as u32,
+            character: content[line_mapping[line]..offset].chars().count() as u32,
+        }
+    }
+
     fn simple_global_search(url: &Url, tree: &Tree, content: &str, query_str: &str, line_mapping: &[usize]) -> Vec<Location> {
         let query = Query::new(tree_sitter_glsl::language(), query_str).unwrap();
         let mut query_cursor = QueryCursor::new();
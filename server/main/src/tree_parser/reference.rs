@@ -1,20 +1,279 @@
+use hashbrown::HashMap;
+
+use super::declaration::Declaration;
 use super::*;
 
-fn function_ref_pattern(name: &str) -> String {
-    r#"((call_expression(identifier) @call)(#match? @call "^"#.to_owned() + name + r#"$"))"#
+/// What a name in code refers to
+pub enum Symbol {
+    /// A local variable or parameter, `definition` is the byte offset of its defining identifier
+    Local { name: String, definition: usize },
+    /// A function, global variable, uniform, struct or macro
+    Global { name: String },
+    /// A field of a struct
+    Field { struct_name: String, name: String },
 }
 
-impl TreeParser {
-    pub fn find_references(url: &Url, position: Position, tree: &Tree, content: &str, line_mapping: &[usize]) -> Option<Vec<Location>> {
-        let current_node = Self::current_node_fetch(position, tree, content, line_mapping)?;
-        let parent = current_node.parent()?;
+impl Symbol {
+    pub fn name(&self) -> &str {
+        match self {
+            Symbol::Local { name, .. } | Symbol::Global { name } | Symbol::Field { name, .. } => name,
+        }
+    }
+
+    /// Name of the global definition that decides where this symbol is visible
+    pub fn global_name(&self) -> Option<&str> {
+        match self {
+            Symbol::Local { .. } => None,
+            Symbol::Global { name } => Some(name),
+            Symbol::Field { struct_name, .. } => Some(struct_name),
+        }
+    }
+}
+
+/// A place where a symbol is referred
+pub struct Reference {
+    pub range: Range,
+    /// Whether the symbol is defined here
+    pub is_definition: bool,
+}
+
+/// Types of global variables and struct fields, used to find which struct a field expression refers to
+#[derive(Default)]
+pub struct TypeTable {
+    variables: HashMap<String, String>,
+    fields: HashMap<(String, String), String>,
+}
+
+/// Name of a declared type, structs declared inline are named by their names
+fn type_name(type_node: Node, content: &str) -> Option<String> {
+    let type_node = match type_node.kind() {
+        "struct_specifier" => type_node.child_by_field_name("name")?,
+        _ => type_node,
+    };
+    Some(type_node.utf8_text(content.as_bytes()).unwrap().to_owned())
+}
+
+/// Field identifiers declared by a field declaration
+fn field_identifiers(field_declaration: Node) -> Vec<Node> {
+    let mut cursor = field_declaration.walk();
+    field_declaration
+        .children_by_field_name("declarator", &mut cursor)
+        .filter_map(|mut declarator| {
+            while declarator.kind() != "field_identifier" {
+                declarator = declarator.child_by_field_name("declarator")?;
+            }
+            Some(declarator)
+        })
+        .collect()
+}
+
+/// Struct of the field list holding this field declarator
+fn declaring_struct(field: Node) -> Option<Node> {
+    let mut current = field.parent()?;
+    while current.kind() == "array_declarator" {
+        current = current.parent()?;
+    }
+    if current.kind() != "field_declaration" {
+        return None;
+    }
+    current
+        .parent()
+        .and_then(|list| list.parent())
+        .filter(|node| node.kind() == "struct_specifier")
+}
+
+impl TypeTable {
+    /// Add global variables and struct fields of this file
+    pub fn extend(&mut self, tree: &Tree, content: &str) {
+        for declaration in TreeParser::global_declarations(tree) {
+            if let Some(type_name) = type_name(declaration.type_node, content) {
+                for (name, _) in &declaration.declarators {
+                    let name = name.utf8_text(content.as_bytes()).unwrap().to_owned();
+                    self.variables.insert(name, type_name.clone());
+                }
+            }
+        }
+        for definition in TreeParser::global_definitions(tree) {
+            if definition.node.kind() != "struct_specifier" {
+                continue;
+            }
+            let struct_name = definition.name.utf8_text(content.as_bytes()).unwrap();
+            let body = match definition.node.child_by_field_name("body") {
+                Some(body) => body,
+                None => continue,
+            };
+            let mut cursor = body.walk();
+            for field_declaration in body.named_children(&mut cursor) {
+                let field_type = match field_declaration.child_by_field_name("type") {
+                    Some(field_type) => field_type.utf8_text(content.as_bytes()).unwrap(),
+                    None => continue,
+                };
+                for field in field_identifiers(field_declaration) {
+                    let field = field.utf8_text(content.as_bytes()).unwrap().to_owned();
+                    self.fields.insert((struct_name.to_owned(), field), field_type.to_owned());
+                }
+            }
+        }
+    }
+
+    fn has_field(&self, struct_name: &str, field: &str) -> bool {
+        self.fields.contains_key(&(struct_name.to_owned(), field.to_owned()))
+    }
+
+    /// Type name of an expression, only variables, fields and their array elements are resolved
+    fn type_of(&self, expression: Node, content: &str) -> Option<String> {
+        match expression.kind() {
+            "identifier" => match TreeParser::local_definition(expression, content) {
+                Some(definition) => {
+                    let type_node = match definition.node.kind() {
+                        "declaration" => Declaration::new(definition.node)?.type_node,
+                        _ => definition.node.child_by_field_name("type")?,
+                    };
+                    type_name(type_node, content)
+                }
+                None => self.variables.get(expression.utf8_text(content.as_bytes()).unwrap()).cloned(),
+            },
+            "field_expression" => {
+                let struct_name = self.type_of(expression.child_by_field_name("argument")?, content)?;
+                let field = expression
+                    .child_by_field_name("field")?
+                    .utf8_text(content.as_bytes())
+                    .unwrap()
+                    .to_owned();
+                self.fields.get(&(struct_name, field)).cloned()
+            }
+            "subscript_expression" => self.type_of(expression.child_by_field_name("argument")?, content),
+            "parenthesized_expression" => self.type_of(expression.named_child(0)?, content),
+            _ => None,
+        }
+    }
+}
 
-        match (current_node.kind(), parent.kind()) {
-            (_, "function_declarator") | (_, "preproc_function_def") => {
-                let query_str = function_ref_pattern(current_node.utf8_text(content.as_bytes()).unwrap());
-                Some(Self::simple_global_search(url, tree, content, &query_str, line_mapping))
+impl TreeParser {
+    /// The symbol named at this position
+    pub fn symbol_at(position: Position, tree: &Tree, content: &str, line_mapping: &[usize], types: &TypeTable) -> Option<Symbol> {
+        let node = Self::current_node_fetch(position, tree, content, line_mapping)?;
+        let name = node.utf8_text(content.as_bytes()).unwrap().to_owned();
+        match node.kind() {
+            // Macro parameters are only used in the raw text of macro values
+            "identifier" if node.parent().is_some_and(|parent| parent.kind() == "preproc_params") => None,
+            "identifier" | "type_identifier" => match Self::local_definition(node, content) {
+                Some(definition) => Some(Symbol::Local {
+                    name,
+                    definition: definition.name.start_byte(),
+                }),
+                None => Some(Symbol::Global { name }),
+            },
+            "field_identifier" => {
+                let struct_name = match declaring_struct(node) {
+                    Some(struct_node) => type_name(struct_node, content)?,
+                    None => {
+                        let parent = node.parent().filter(|parent| parent.kind() == "field_expression")?;
+                        types.type_of(parent.child_by_field_name("argument")?, content)?
+                    }
+                };
+                Some(Symbol::Field { struct_name, name })
             }
             _ => None,
         }
     }
+
+    /// References of this symbol in a file, including where it is defined
+    pub fn symbol_references(symbol: &Symbol, tree: &Tree, content: &str, line_mapping: &[usize], types: &TypeTable) -> Vec<Reference> {
+        let name = symbol.name();
+        let is_name = |node: Node| node.utf8_text(content.as_bytes()).unwrap() == name;
+        let global_definitions = match symbol {
+            Symbol::Global { .. } => Self::global_definitions(tree)
+                .into_iter()
+                .map(|definition| definition.name.start_byte())
+                .collect::<Vec<_>>(),
+            _ => vec![],
+        };
+
+        let mut references = vec![];
+        for node in named_descendants(tree.root_node()) {
+            let is_definition = match (symbol, node.kind()) {
+                (Symbol::Local { definition, .. }, "identifier") if is_name(node) => {
+                    if node.start_byte() == *definition {
+                        Some(true)
+                    } else {
+                        Self::local_definition(node, content)
+                            .filter(|local| local.name.start_byte() == *definition)
+                            .map(|_| false)
+                    }
+                }
+                (Symbol::Global { .. }, "identifier" | "type_identifier") if is_name(node) => {
+                    let is_parameter = node.parent().is_some_and(|parent| parent.kind() == "preproc_params");
+                    (!is_parameter && Self::local_definition(node, content).is_none())
+                        .then(|| global_definitions.contains(&node.start_byte()))
+                }
+                // Macro values are not parsed, names in them are matched as words
+                (Symbol::Global { .. }, "preproc_arg") => {
+                    let text = node.utf8_text(content.as_bytes()).unwrap();
+                    let is_word = |char: Option<char>| char.is_some_and(|char| char.is_ascii_alphanumeric() || char == '_');
+                    for (index, _) in text.match_indices(name) {
+                        let before = text[..index].chars().next_back();
+                        let after = text[index + name.len()..].chars().next();
+                        if !is_word(before) && before != Some('.') && !is_word(after) {
+                            let start = node.start_byte() + index;
+                            references.push(Reference {
+                                range: Range {
+                                    start: Self::offset_position(content, line_mapping, start),
+                                    end: Self::offset_position(content, line_mapping, start + name.len()),
+                                },
+                                is_definition: false,
+                            });
+                        }
+                    }
+                    None
+                }
+                (Symbol::Field { struct_name, .. }, "field_identifier") if is_name(node) => match declaring_struct(node) {
+                    Some(struct_node) => type_name(struct_node, content).filter(|name| name == struct_name).map(|_| true),
+                    None => node
+                        .parent()
+                        .filter(|parent| parent.kind() == "field_expression")
+                        .and_then(|parent| types.type_of(parent.child_by_field_name("argument")?, content))
+                        .filter(|name| name == struct_name)
+                        .map(|_| false),
+                },
+                _ => None,
+            };
+            if let Some(is_definition) = is_definition {
+                references.push(Reference {
+                    range: node.to_range(content, line_mapping),
+                    is_definition,
+                });
+            }
+        }
+        references
+    }
+
+    /// Whether renaming this symbol to `new_name` collides with definitions or struct fields in this file.
+    ///
+    /// Local variables named `new_name` would shadow the renamed symbol at its references.
+    pub fn rename_collides(
+        symbol: &Symbol, new_name: &str, references: &[Reference], tree: &Tree, content: &str, line_mapping: &[usize],
+    ) -> bool {
+        match symbol {
+            Symbol::Field { struct_name, .. } => {
+                let mut types = TypeTable::default();
+                types.extend(tree, content);
+                types.has_field(struct_name, new_name)
+            }
+            _ if Self::defines(new_name, tree, content) => true,
+            _ => references.iter().any(|reference| {
+                let offset = byte_index(content, reference.range.start, line_mapping).0;
+                tree.root_node()
+                    .descendant_for_byte_range(offset, offset)
+                    .is_some_and(|node| Self::scope_definition(node, new_name, content).is_some())
+            }),
+        }
+    }
+
+    /// Whether this file defines the global of this name
+    pub fn defines(name: &str, tree: &Tree, content: &str) -> bool {
+        Self::global_definitions(tree)
+            .iter()
+            .any(|definition| definition.name.utf8_text(content.as_bytes()).unwrap() == name)
+    }
 }
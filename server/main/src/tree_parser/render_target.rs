@@ -0,0 +1,194 @@
+use regex::Regex;
+
+use super::*;
+
+lazy_static! {
+    static ref BUFFER_QUERY: Query = Query::new(
+        tree_sitter_glsl::language(),
+        r#"((identifier) @buffer (#match? @buffer "^(colortex[0-9]+|gcolor|gdepth|gnormal|composite|gaux[1-4]|shadowcolor[0-9]*)$"))"#
+    )
+    .unwrap();
+    static ref FRAGMENT_OUTPUT_QUERY: Query = Query::new(
+        tree_sitter_glsl::language(),
+        r#"
+        (subscript_expression argument: (identifier) @data index: (_) @index (#eq? @data "gl_FragData"))
+        ((identifier) @color (#eq? @color "gl_FragColor"))
+        "#
+    )
+    .unwrap();
+    static ref COMMENT_QUERY: Query = Query::new(tree_sitter_glsl::language(), "(comment) @comment").unwrap();
+    static ref RE_DRAWBUFFERS: Regex = Regex::new(r"^/\*\s*DRAWBUFFERS\s*:\s*([0-9A-Fa-f]+)\s*\*/$").unwrap();
+    static ref RE_RENDERTARGETS: Regex = Regex::new(r"^/\*\s*RENDERTARGETS\s*:\s*([0-9]+(?:\s*,\s*[0-9]+)*)\s*\*/$").unwrap();
+    static ref RE_BUFFER_CLEAR: Regex = Regex::new(r"^(colortex[0-9]+|shadowcolor[0-9]+)Clear$").unwrap();
+}
+
+/// A `DRAWBUFFERS` or `RENDERTARGETS` comment directive
+pub struct RenderTargetDirective {
+    /// Buffer indexes in the order they bind to fragment outputs
+    pub targets: Vec<u8>,
+    pub range: Range,
+}
+
+/// A fragment output, declared by `out` or written to `gl_FragData` and `gl_FragColor`
+pub struct FragmentOutput {
+    pub name: String,
+    /// Location by `layout(location = N)` or index of `gl_FragData`. `None` if not specified or not a constant
+    pub location: Option<u32>,
+    /// Number of locations taken, greater than 1 for output arrays
+    pub size: u32,
+    /// Declared by `out` instead of built-in variables
+    pub declared: bool,
+    pub range: Range,
+}
+
+impl TreeParser {
+    /// Find the last render target directive in this file.
+    pub fn render_target_directive(tree: &Tree, content: &str, line_mapping: &[usize]) -> Option<RenderTargetDirective> {
+        let root_node = tree.root_node();
+        let mut cursor = root_node.walk();
+        let mut stack = vec![root_node];
+        let mut directive = None;
+        while let Some(node) = stack.pop() {
+            for child in node.children(&mut cursor) {
+                if child.kind() == "comment" {
+                    let text = child.utf8_text(content.as_bytes()).unwrap();
+                    let targets = if let Some(captures) = RE_DRAWBUFFERS.captures(text) {
+                        let targets = captures.get(1).unwrap().as_str().chars();
+                        targets.map(|char| char.to_digit(16).unwrap() as u8).collect()
+                    } else if let Some(captures) = RE_RENDERTARGETS.captures(text) {
+                        let targets = captures.get(1).unwrap().as_str().split(',');
+                        targets.filter_map(|target| target.trim().parse::<u8>().ok()).collect()
+                    } else {
+                        continue;
+                    };
+                    let range = child.to_range(content, line_mapping);
+                    // Nodes are not visited in order since we are using a stack, keep the last one in the file.
+                    if directive
+                        .as_ref()
+                        .is_none_or(|directive: &RenderTargetDirective| directive.range.start < range.start)
+                    {
+                        directive = Some(RenderTargetDirective { targets, range });
+                    }
+                } else if child.child_count() > 0 {
+                    stack.push(child);
+                }
+            }
+        }
+        directive
+    }
+
+    /// Edits converting `DRAWBUFFERS` directives in this file to `RENDERTARGETS`.
+    pub fn render_targets_edits(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<TextEdit> {
+        let mut query_cursor = QueryCursor::new();
+        query_cursor
+            .matches(&COMMENT_QUERY, tree.root_node(), content.as_bytes())
+            .flat_map(|query_match| query_match.captures.iter())
+            .filter_map(|capture| {
+                let text = capture.node.utf8_text(content.as_bytes()).unwrap();
+                let targets = RE_DRAWBUFFERS.captures(text)?.get(1).unwrap().as_str().chars();
+                let targets = targets.map(|char| char.to_digit(16).unwrap().to_string()).collect::<Vec<_>>();
+                Some(TextEdit {
+                    range: capture.node.to_range(content, line_mapping),
+                    new_text: format!("/* RENDERTARGETS: {} */", targets.join(",")),
+                })
+            })
+            .collect()
+    }
+
+    /// Buffer samplers used in this file (declarations excluded), as canonical buffer names and ranges of usages.
+    pub fn render_target_reads(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<(String, Range)> {
+        let mut query_cursor = QueryCursor::new();
+        query_cursor
+            .matches(&BUFFER_QUERY, tree.root_node(), content.as_bytes())
+            .flat_map(|query_match| query_match.captures.iter())
+            .filter(|capture| capture.node.parent().is_some_and(|parent| parent.kind() != "declaration"))
+            .filter_map(|capture| {
+                let name = capture.node.utf8_text(content.as_bytes()).unwrap();
+                crate::pipeline::canonical_buffer_name(name).map(|name| (name, capture.node.to_range(content, line_mapping)))
+            })
+            .collect()
+    }
+
+    /// Fragment outputs in this file, by `out` declarations and writes to `gl_FragData[N]` and `gl_FragColor`.
+    pub fn fragment_outputs(tree: &Tree, content: &str, line_mapping: &[usize]) -> Vec<FragmentOutput> {
+        let mut outputs = vec![];
+        for declaration in Self::global_declarations(tree) {
+            if !declaration.has_qualifier("out") {
+                continue;
+            }
+            let location = declaration
+                .qualifiers
+                .iter()
+                .find(|qualifier| qualifier.kind() == "layout_specification")
+                .and_then(|layout| Self::layout_location(*layout, content));
+            for (identifier, declarator) in &declaration.declarators {
+                let size = match declarator.kind() {
+                    "array_declarator" => declarator
+                        .child_by_field_name("size")
+                        .and_then(|size| size.utf8_text(content.as_bytes()).unwrap().parse::<u32>().ok())
+                        .unwrap_or(1),
+                    _ => 1,
+                };
+                outputs.push(FragmentOutput {
+                    name: identifier.utf8_text(content.as_bytes()).unwrap().to_owned(),
+                    location,
+                    size,
+                    declared: true,
+                    range: identifier.to_range(content, line_mapping),
+                });
+            }
+        }
+
+        let mut query_cursor = QueryCursor::new();
+        for query_match in query_cursor.matches(&FRAGMENT_OUTPUT_QUERY, tree.root_node(), content.as_bytes()) {
+            let (node, location) = match query_match.captures {
+                [data, index] => (
+                    data.node.parent().unwrap(),
+                    index.node.utf8_text(content.as_bytes()).unwrap().parse::<u32>().ok(),
+                ),
+                [color] => (color.node, Some(0)),
+                _ => continue,
+            };
+            outputs.push(FragmentOutput {
+                name: node.utf8_text(content.as_bytes()).unwrap().to_owned(),
+                location,
+                size: 1,
+                declared: false,
+                range: node.to_range(content, line_mapping),
+            });
+        }
+        outputs
+    }
+
+    /// Value of `location` in a `layout(...)` qualifier
+    fn layout_location(layout: Node, content: &str) -> Option<u32> {
+        let qualifiers = layout.named_child(0)?;
+        let mut cursor = qualifiers.walk();
+        let location = qualifiers.named_children(&mut cursor).find(|qualifier| {
+            qualifier
+                .named_child(0)
+                .is_some_and(|name| name.utf8_text(content.as_bytes()).unwrap() == "location")
+        })?;
+        location.named_child(1)?.utf8_text(content.as_bytes()).unwrap().parse::<u32>().ok()
+    }
+
+    /// Buffers that will not be cleared every frame, declared by `const bool colortexNClear = false;`
+    pub fn persistent_buffers(tree: &Tree, content: &str) -> Vec<String> {
+        let mut buffers = vec![];
+        for declaration in Self::global_declarations(tree) {
+            if !declaration.has_qualifier("const") {
+                continue;
+            }
+            for (identifier, declarator) in &declaration.declarators {
+                let name = identifier.utf8_text(content.as_bytes()).unwrap();
+                let value = declarator.child_by_field_name("value");
+                if let (Some(captures), Some(value)) = (RE_BUFFER_CLEAR.captures(name), value) {
+                    if value.kind() == "false" {
+                        buffers.push(captures.get(1).unwrap().as_str().to_owned());
+                    }
+                }
+            }
+        }
+        buffers
+    }
+}
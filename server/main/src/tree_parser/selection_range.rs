@@ -0,0 +1,42 @@
+use crate::file::byte_index;
+
+use super::*;
+
+impl TreeParser {
+    /// Ranges of nodes containing this position, from the innermost node to the whole file
+    pub fn selection_range(position: Position, tree: &Tree, content: &str, line_mapping: &[usize]) -> SelectionRange {
+        let offset = byte_index(content, position, line_mapping).0;
+        let is_word = |byte: &u8| byte.is_ascii_alphanumeric() || *byte == b'_';
+        // Cursors right after a word select that word
+        let offset = match (
+            content.as_bytes().get(offset),
+            offset.checked_sub(1).map(|offset| content.as_bytes()[offset]),
+        ) {
+            (current, Some(previous)) if !current.is_some_and(is_word) && is_word(&previous) => offset - 1,
+            _ => offset,
+        };
+
+        let mut ranges: Vec<Range> = vec![];
+        let mut current = tree.root_node().named_descendant_for_byte_range(offset, offset);
+        while let Some(node) = current {
+            let range = node.to_range(content, line_mapping);
+            // Parents spanning the same text do not expand the selection
+            if ranges.last() != Some(&range) {
+                ranges.push(range);
+            }
+            current = node.parent();
+        }
+
+        let mut selection_range: Option<SelectionRange> = None;
+        for range in ranges.into_iter().rev() {
+            selection_range = Some(SelectionRange {
+                range,
+                parent: selection_range.map(Box::new),
+            });
+        }
+        selection_range.unwrap_or(SelectionRange {
+            range: Range::new(position, position),
+            parent: None,
+        })
+    }
+}
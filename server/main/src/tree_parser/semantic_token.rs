@@ -0,0 +1,177 @@
+use hashbrown::HashMap;
+
+use crate::builtin::{builtins_named, BuiltinKind, KEYWORDS};
+
+use super::declaration::Declaration;
+use super::*;
+
+/// Token types in the legend, indexed by the constants below
+pub const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::MACRO,
+    SemanticTokenType::STRUCT,
+    SemanticTokenType::PROPERTY,
+];
+
+/// Token modifiers in the legend, `optifine` marks uniforms and attributes provided by OptiFine or Iris
+pub const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+    SemanticTokenModifier::new("global"),
+    SemanticTokenModifier::new("uniform"),
+    SemanticTokenModifier::new("input"),
+    SemanticTokenModifier::new("output"),
+    SemanticTokenModifier::new("optifine"),
+];
+
+const FUNCTION: u32 = 0;
+const PARAMETER: u32 = 1;
+const VARIABLE: u32 = 2;
+const MACRO: u32 = 3;
+const STRUCT: u32 = 4;
+const PROPERTY: u32 = 5;
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+const DEFAULT_LIBRARY: u32 = 1 << 2;
+const GLOBAL: u32 = 1 << 3;
+const UNIFORM: u32 = 1 << 4;
+const INPUT: u32 = 1 << 5;
+const OUTPUT: u32 = 1 << 6;
+const OPTIFINE: u32 = 1 << 7;
+
+/// Whether this name is a uniform or attribute provided by shader loaders
+fn is_loader_provided(name: &str) -> bool {
+    builtins_named(name)
+        .iter()
+        .any(|builtin| matches!(builtin.kind, BuiltinKind::Uniform | BuiltinKind::Attribute))
+}
+
+/// Token type and modifiers of global names, collected from files visible to the highlighted file
+#[derive(Default)]
+pub struct TokenKinds(HashMap<String, (u32, u32)>);
+
+impl TokenKinds {
+    /// Add functions, global variables, structs and macros of this file
+    pub fn extend(&mut self, tree: &Tree, content: &str) {
+        for definition in TreeParser::global_definitions(tree) {
+            let name = definition.name.utf8_text(content.as_bytes()).unwrap();
+            let kind = match definition.node.kind() {
+                "function_definition" => (FUNCTION, GLOBAL),
+                "struct_specifier" => (STRUCT, GLOBAL),
+                "preproc_def" | "preproc_function_def" => (MACRO, GLOBAL),
+                _ => {
+                    let declaration = match Declaration::new(definition.node) {
+                        Some(declaration) => declaration,
+                        None => continue,
+                    };
+                    let mut modifiers = GLOBAL;
+                    for (qualifier, modifier) in [
+                        ("const", READONLY),
+                        ("uniform", UNIFORM),
+                        ("in", INPUT),
+                        ("attribute", INPUT),
+                        ("out", OUTPUT),
+                        ("varying", INPUT | OUTPUT),
+                    ] {
+                        if declaration.has_qualifier(qualifier) {
+                            modifiers |= modifier;
+                        }
+                    }
+                    if modifiers & (UNIFORM | INPUT) != 0 && is_loader_provided(name) {
+                        modifiers |= OPTIFINE;
+                    }
+                    (VARIABLE, modifiers)
+                }
+            };
+            self.0.insert(name.to_owned(), kind);
+        }
+    }
+}
+
+impl TreeParser {
+    /// Token type and modifiers of an identifier, `None` for names unknown to this file
+    fn identifier_token(node: Node, name: &str, content: &str, globals: &TokenKinds, definitions: &[usize]) -> Option<(u32, u32)> {
+        let parent = node.parent()?;
+        match parent.kind() {
+            "preproc_params" => return Some((PARAMETER, DECLARATION)),
+            "preproc_def" | "preproc_function_def" => return Some((MACRO, DECLARATION | GLOBAL)),
+            "preproc_ifdef" | "preproc_defined" => return Some((MACRO, GLOBAL)),
+            _ => {}
+        }
+        if let Some(definition) = Self::local_definition(node, content) {
+            let declaration = if definition.name == node { DECLARATION } else { 0 };
+            return match definition.node.kind() {
+                "parameter_declaration" => Some((PARAMETER, declaration)),
+                _ => {
+                    let readonly = Declaration::new(definition.node)
+                        .filter(|local| local.has_qualifier("const"))
+                        .map_or(0, |_| READONLY);
+                    Some((VARIABLE, declaration | readonly))
+                }
+            };
+        }
+        if let Some((token_type, modifiers)) = globals.0.get(name) {
+            let declaration = if definitions.contains(&node.start_byte()) { DECLARATION } else { 0 };
+            return Some((*token_type, modifiers | declaration));
+        }
+        match builtins_named(name).first().map(|builtin| builtin.kind) {
+            Some(BuiltinKind::Function) => Some((FUNCTION, DEFAULT_LIBRARY)),
+            Some(BuiltinKind::Variable) => Some((VARIABLE, DEFAULT_LIBRARY)),
+            Some(BuiltinKind::Uniform) => Some((VARIABLE, GLOBAL | UNIFORM | OPTIFINE)),
+            Some(BuiltinKind::Attribute) => Some((VARIABLE, GLOBAL | INPUT | OPTIFINE)),
+            // Functions declared by prototypes only, while type constructors are left to grammars of editors
+            None if parent.kind() == "call_expression" && !KEYWORDS.contains(&name) => Some((FUNCTION, 0)),
+            None => None,
+        }
+    }
+
+    /// Semantic tokens of identifiers in this file, `globals` classifies names defined in other files
+    pub fn semantic_tokens(tree: &Tree, content: &str, line_mapping: &[usize], globals: &TokenKinds) -> Vec<SemanticToken> {
+        let definitions = Self::global_definitions(tree)
+            .iter()
+            .map(|definition| definition.name.start_byte())
+            .collect::<Vec<_>>();
+
+        let mut tokens = vec![];
+        let mut previous = Position::default();
+        for node in named_descendants(tree.root_node()) {
+            let name = node.utf8_text(content.as_bytes()).unwrap();
+            let token = match node.kind() {
+                "identifier" => Self::identifier_token(node, name, content, globals, &definitions),
+                "type_identifier" => match globals.0.get(name) {
+                    Some((STRUCT, modifiers)) => {
+                        let declaration = if definitions.contains(&node.start_byte()) { DECLARATION } else { 0 };
+                        Some((STRUCT, modifiers | declaration))
+                    }
+                    _ => None,
+                },
+                "field_identifier" => match node.parent().map(|parent| parent.kind()) {
+                    Some("field_expression") => Some((PROPERTY, 0)),
+                    _ => Some((PROPERTY, DECLARATION)),
+                },
+                _ => None,
+            };
+            if let Some((token_type, token_modifiers_bitset)) = token {
+                let range = node.to_range(content, line_mapping);
+                let delta_line = range.start.line - previous.line;
+                let delta_start = match delta_line {
+                    0 => range.start.character - previous.character,
+                    _ => range.start.character,
+                };
+                tokens.push(SemanticToken {
+                    delta_line,
+                    delta_start,
+                    length: range.end.character - range.start.character,
+                    token_type,
+                    token_modifiers_bitset,
+                });
+                previous = range.start;
+            }
+        }
+        tokens
+    }
+}
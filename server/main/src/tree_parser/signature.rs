@@ -0,0 +1,100 @@
+use super::definition::Definition;
+use super::*;
+
+/// Byte offsets of parameters in a signature text, from the start of the signature
+fn parameter_offsets(parameters: Node, start_byte: usize) -> Vec<(usize, usize)> {
+    let mut cursor = parameters.walk();
+    parameters
+        .named_children(&mut cursor)
+        .filter(|parameter| matches!(parameter.kind(), "parameter_declaration" | "identifier"))
+        .map(|parameter| (parameter.start_byte() - start_byte, parameter.end_byte() - start_byte))
+        .collect()
+}
+
+/// Signature information of this label, with parameters at these byte offsets in the label
+pub fn signature_information(label: String, offsets: &[(usize, usize)], documentation: Option<String>) -> SignatureInformation {
+    let char_offset = |byte_offset: usize| label[..byte_offset].chars().count() as u32;
+    SignatureInformation {
+        parameters: Some(
+            offsets
+                .iter()
+                .map(|(start, end)| ParameterInformation {
+                    label: ParameterLabel::LabelOffsets([char_offset(*start), char_offset(*end)]),
+                    documentation: None,
+                })
+                .collect(),
+        ),
+        label,
+        documentation: documentation.map(|value| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            })
+        }),
+        active_parameter: None,
+    }
+}
+
+impl Definition<'_> {
+    /// Signature of functions and function-like macros, with offsets of their parameters
+    fn signature_information(&self, content: &str) -> Option<SignatureInformation> {
+        let parameters = match self.node.kind() {
+            "function_definition" => self.name.parent()?.child_by_field_name("parameters")?,
+            "preproc_function_def" => self.node.child_by_field_name("parameters")?,
+            _ => return None,
+        };
+        let offsets = parameter_offsets(parameters, self.node.start_byte());
+        Some(signature_information(
+            self.signature(content),
+            &offsets,
+            self.documentation(content),
+        ))
+    }
+}
+
+impl TreeParser {
+    /// Name of the function called at this position, and the index of the argument the cursor is in
+    pub fn current_call(position: Position, tree: &Tree, content: &str, line_mapping: &[usize]) -> Option<(String, u32)> {
+        let offset = byte_index(content, position, line_mapping).0;
+        let mut current = tree.root_node().descendant_for_byte_range(offset, offset);
+        while let Some(node) = current {
+            let close_start = node
+                .child(node.child_count().saturating_sub(1))
+                .filter(|last| last.kind() == ")" && !last.is_missing())
+                .map_or(node.end_byte(), |last| last.start_byte());
+            if node.kind() == "argument_list" && node.start_byte() < offset && offset <= close_start {
+                let function = node.parent()?.child_by_field_name("function")?;
+                // Commas after incomplete arguments are wrapped by errors
+                let mut cursor = node.walk();
+                let active_parameter = node
+                    .children(&mut cursor)
+                    .filter(|child| child.start_byte() < offset)
+                    .map(|child| match child.kind() {
+                        "," => 1,
+                        "ERROR" => {
+                            let mut cursor = child.walk();
+                            let count = child
+                                .children(&mut cursor)
+                                .filter(|child| child.kind() == "," && child.start_byte() < offset)
+                                .count();
+                            count
+                        }
+                        _ => 0,
+                    })
+                    .sum::<usize>();
+                return Some((function.utf8_text(content.as_bytes()).ok()?.to_owned(), active_parameter as u32));
+            }
+            current = node.parent();
+        }
+        None
+    }
+
+    /// Signatures of functions and function-like macros with this name in this file
+    pub fn function_signatures(name: &str, tree: &Tree, content: &str) -> Vec<SignatureInformation> {
+        Self::global_definitions(tree)
+            .iter()
+            .filter(|definition| definition.name.utf8_text(content.as_bytes()).unwrap() == name)
+            .filter_map(|definition| definition.signature_information(content))
+            .collect()
+    }
+}